@@ -25,6 +25,8 @@ fn main() {
         r#"{}_n C_k , \ \binom{n}{k} , \ \binom12 , \ \tbinom{n}{k} , \ \dbinom{n}{k}"#,
         r#"\forall \epsilon > 0 \exists \delta > 0 \forall y \left[ | y - x | < \delta \Rightarrow | f ( y ) - f ( x ) | < \epsilon \right]"#,
         r#"\phi = 1 + \frac{ 1 }{ 1 + \frac{ 1 }{ 1 + \frac{ 1 }{ \ddots } } }"#,
+        r#"\phi = 1 + \cfrac{ 1 }{ 1 + \cfrac{ 1 }{ 1 + \cfrac{ 1 }{ \ddots } } }"#,
+        r#"\dfrac{ a }{ b } , \ \tfrac{ a }{ b } , \ \sfrac{ 1 }{ 2 } , \ {n \choose k} , \ \genfrac{[}{]}{0pt}{}{n}{k}"#,
         r#"G / \ker f \cong \mathrm{im}\,f"#,
         r#"\iint_S ( \bm{\nabla} \times \bm{A} ) \cdot d\bm{S} = \oint_C \bm{A} \cdot d\bm{l}"#,
         r#"\int \mathscr{D}\!x = \lim_{N \to \infty} \left( \frac{ m }{ 2 \pi i \hbar \Delta t } \right)^\frac{N}{2} \int\!\cdots\!\int \prod_{i=1}^{N-1} dx_i"#,
@@ -53,6 +55,9 @@ fn main() {
         \begin{bmatrix} a & b \\ c & d \end{bmatrix} , 
         \begin{vmatrix} a & b \\ c & d \end{vmatrix}"#,
         r#"\begin{align} f ( x ) &= x^2 + 2 x + 1 \\ &= ( x + 1 )^2\end{align}"#,
+        r#"\boxed{ E = m c^2 } , \ \enclose{circle}{ x } , \ \bbox[yellow,5px]{ \fbox{ area } = \pi r^2 }"#,
+        r#"\frac{ \cancel{ 2 } x }{ \cancel{ 2 } y } = \frac{ x }{ y } , \ \cancelto{0}{ \bcancel{ a } - a }"#,
+        r#"{ \color{red} a^2 } + \textcolor{ForestGreen}{ b^2 } = \colorbox{yellow}{ hypotenuse }^2"#,
         r#"\lambda_\text{Compton} = \frac{ 2 \pi \hbar }{ m c }"#,
         r#"\int Y_{\ell m} ( \Omega ) Y_{\ell' m'} ( \Omega ) \, d^2 \Omega = \delta_{\ell \ell'} \delta_{m m'}"#,
     ];
@@ -0,0 +1,59 @@
+//! Generate the symbol table in `src/symbol.rs` from `unicode-math-table.tex`.
+//!
+//! `unicode-math-table.tex` is a part of the unicode-math package (LPPL 1.3c):
+//! https://github.com/latex3/unicode-math
+//!
+//! ```sh
+//! cargo run --example symbol_table -- path/to/unicode-math-table.tex
+//! ```
+//!
+//! The output replaces the entries of `SYMBOLS` in `src/symbol.rs`.
+
+use std::{env, fs, collections::BTreeMap};
+
+fn main() {
+    let path = env::args().nth(1).expect("usage: symbol_table <unicode-math-table.tex>");
+    let table = fs::read_to_string(&path).expect("cannot read the table");
+
+    // コマンド名の順に並べる. 同じコマンド名が複数ある場合は最初のものを使う
+    let mut symbols = BTreeMap::new();
+    for line in table.lines() {
+        if let Some((name, c, class)) = parse_line(line) {
+            symbols.entry(name).or_insert((c, class));
+        }
+    }
+
+    for (name, (c, class)) in symbols.iter() {
+        let c = match c {
+            '\\' => "\\\\".to_owned(),
+            '"'  => "\\\"".to_owned(),
+            c    => c.to_string(),
+        };
+        println!("    {:<25} \"{}\", {}),", format!("(\"{}\",", name), c, class);
+    }
+}
+
+/// `\UnicodeMathSymbol{"1D400}{\mbfA }{\mathalpha}{MATHEMATICAL BOLD CAPITAL A}%` を読み取る.
+///
+/// アクセントや根号など, 記号として置けないクラスのものは除く.
+fn parse_line(line: &str) -> Option<(String, char, &'static str)> {
+    let rest = line.trim().strip_prefix(r#"\UnicodeMathSymbol{""#)?;
+    let (code, rest) = rest.split_once('}')?;
+    let (name, rest) = rest.strip_prefix(r"{\")?.split_once('}')?;
+    let (class, _) = rest.strip_prefix(r"{\")?.split_once('}')?;
+
+    let c = char::from_u32(u32::from_str_radix(code, 16).ok()?)?;
+    let class = match class.trim() {
+        "mathalpha" => "Alpha",
+        "mathord"   => "Ord",
+        "mathop"    => "Op",
+        "mathbin"   => "Bin",
+        "mathrel"   => "Rel",
+        "mathopen"  => "Open",
+        "mathclose" => "Close",
+        "mathfence" => "Fence",
+        "mathpunct" => "Punct",
+        _ => return None,
+    };
+    Some((name.trim().to_owned(), c, class))
+}
@@ -1,61 +1,78 @@
 use std::fmt;
-use super::attribute::{Variant, Accent, LineThickness, ColumnAlign};
+use super::attribute::{Variant, Accent, Stretchy, LineThickness, FracAttr, ColumnAlign, Length, MathClass};
 use crate::DisplayStyle;
 
 /// AST node
 #[derive(Debug, Clone, PartialEq)]
 pub enum Node {
-    Number(String),
+    Number(String, Variant),
     Letter(char, Variant),
-    Operator(char),
-    Function(String, Option<Box<Node>>),
-    Space(f32),
+    Operator(char, Variant),
+    Function(String, Variant, Option<Box<Node>>),
+    Space(Length),
     Subscript(Box<Node>, Box<Node>),
     Superscript(Box<Node>, Box<Node>),
     SubSup{ target: Box<Node>, sub: Box<Node>, sup: Box<Node>},
-    OverOp(char, Accent, Box<Node>),
-    UnderOp(char, Accent, Box<Node>),
+    OverOp(char, Accent, Stretchy, Box<Node>),
+    UnderOp(char, Accent, Stretchy, Box<Node>),
     Overset{over: Box<Node>, target: Box<Node>},
     Underset{under: Box<Node>, target: Box<Node>},
     Under(Box<Node>, Box<Node>),
     UnderOver { target: Box<Node>, under: Box<Node>, over: Box<Node>},
     Sqrt(Option<Box<Node>>, Box<Node>),
-    Frac(Box<Node>, Box<Node>, LineThickness),
+    Frac(Box<Node>, Box<Node>, LineThickness, FracAttr),
     Row(Vec<Node>),
     Fenced { open: &'static str, close: &'static str, content: Box<Node> },
     StrechedOp(bool, String),
     OtherOperator(&'static str),
-    SizedParen{ size: &'static str, paren: &'static str },
-    Text(String),
+    SizedParen{ size: &'static str, form: Option<&'static str>, paren: &'static str },
+    Text(String, Variant),
     Matrix(Vec<Node>, ColumnAlign),
     Ampersand,
-    NewLine,
+    NewLine(Option<Length>),
+    HDotsFor(usize),
     Slashed(Box<Node>),
-    Style(Option<DisplayStyle>, Box<Node>),
+    Enclose { notation: String, attrs: Vec<(String, String)>, content: Box<Node> },
+    Padded { attrs: Vec<(&'static str, String)>, content: Box<Node> },
+    Phantom(Box<Node>),
+    Atom { class: MathClass, movablelimits: Option<bool>, content: Box<Node> },
+    Html { attrs: Vec<(String, String)>, content: Box<Node> },
+    Style(Option<DisplayStyle>, Option<u8>, Box<Node>),
+    Color(String, Box<Node>),
     Undefined(String),
 }
 
 impl fmt::Display for Node {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Node::Number(number)  => write!(f, "<mn>{}</mn>", number),
+            Node::Number(number, var) => match var {
+                Variant::Normal => write!(f, "<mn>{}</mn>", number),
+                var             => write!(f, r#"<mn mathvariant="{}">{}</mn>"#, var, number),
+            },
             Node::Letter(letter, var) => match var {
                 Variant::Italic => write!(f, "<mi>{}</mi>", letter),
                 var             => write!(f, r#"<mi mathvariant="{}">{}</mi>"#, var, letter),
             },
-            Node::Operator(op) => if op == &'∂' {
-                write!(f, r#"<mo mathvariant="italic">∂</mo>"#)
-            } else { write!(f, r#"<mo>{}</mo>"#, op) },
-            Node::Function(fun, arg) => match arg {
-                Some(arg) => write!(f, "<mi>{}</mi><mo>&#x2061;</mo>{}", fun, arg),
-                None      => write!(f, "<mi>{}</mi>", fun),
+            Node::Operator(op, var) => match var {
+                Variant::Normal => write!(f, r#"<mo>{}</mo>"#, escape(&op.to_string())),
+                var             => write!(f, r#"<mo mathvariant="{}">{}</mo>"#, var, escape(&op.to_string())),
             },
-            Node::Space(space) => write!(f, r#"<mspace width="{}em"/>"#, space),
+            Node::Function(fun, var, arg) => {
+                match var {
+                    Variant::Normal => write!(f, "<mi>{}</mi>", fun)?,
+                    var             => write!(f, r#"<mi mathvariant="{}">{}</mi>"#, var, fun)?,
+                }
+                match arg {
+                    Some(arg) => write!(f, "<mo>&#x2061;</mo>{}", arg),
+                    None      => Ok(()),
+                }
+            },
+            Node::Space(space) => write!(f, r#"<mspace width="{}"/>"#, space),
             Node::Subscript(a, b) => write!(f, "<msub>{}{}</msub>", a, b),
             Node::Superscript(a, b) => write!(f, "<msup>{}{}</msup>", a, b),
             Node::SubSup{target, sub, sup} => write!(f, "<msubsup>{}{}{}</msubsup>", target, sub, sup),
-            Node::OverOp(op, acc, target) => write!(f, r#"<mover>{}<mo accent="{}">{}</mo></mover>"#, target, acc, op),
-            Node::UnderOp(op, acc, target) => write!(f, r#"<munder>{}<mo accent="{}">{}</mo></munder>"#, target, acc, op),
+            Node::OverOp(op, acc, stretchy, target) => write!(f, r#"<mover accent="{}">{}<mo stretchy="{}">{}</mo></mover>"#, acc, target, stretchy, op),
+            Node::UnderOp(op, acc, stretchy, target) => write!(f, r#"<munder accentunder="{}">{}<mo stretchy="{}">{}</mo></munder>"#, acc, target, stretchy, op),
             Node::Overset{over, target} => write!(f, r#"<mover>{}{}</mover>"#, target, over),
             Node::Underset{under, target} => write!(f, r#"<munder>{}{}</munder>"#, target, under),
             Node::Under(target, under) => write!(f, r#"<munder>{}{}</munder>"#, target, under),
@@ -64,7 +81,7 @@ impl fmt::Display for Node {
                 Some(deg) => write!(f, "<mroot>{}{}</mroot>", content, deg),
                 None      => write!(f, "<msqrt>{}</msqrt>", content),
             },
-            Node::Frac(num, denom, lt) => write!(f, "<mfrac{}>{}{}</mfrac>", lt, num, denom),
+            Node::Frac(num, denom, lt, attr) => write!(f, "<mfrac{}{}>{}{}</mfrac>", lt, attr, num, denom),
             Node::Row(vec) => write!(f, "<mrow>{}</mrow>", 
                 vec.iter().map(|node| format!("{}", node)).collect::<String>()
             ),
@@ -73,46 +90,160 @@ impl fmt::Display for Node {
             },
             Node::StrechedOp(stretchy, op) => write!(f, r#"<mo stretchy="{}">{}</mo>"#, stretchy, op),
             Node::OtherOperator(op) => write!(f, "<mo>{}</mo>", op),
-            Node::SizedParen{size, paren} => write!(f, r#"<mrow><mo maxsize="{0}" minsize="{0}">{1}</mro></mrow>"#, size, paren),
+            Node::SizedParen{size, form, paren} => {
+                write!(f, "<mo")?;
+                if let Some(form) = form {
+                    write!(f, r#" form="{}""#, form)?;
+                }
+                write!(f, r#" stretchy="true" symmetric="true" maxsize="{0}" minsize="{0}">{1}</mo>"#, size, paren)
+            },
             Node::Slashed(node) => match &**node {
                 Node::Letter(x, var) => write!(f, "<mi mathvariant=\"{}\">{}&#x0338;</mi>", var, x),
-                Node::Operator(x) => write!(f, "<mo>{}&#x0338;</mo>", x),
-                n => write!(f, "{}", n),
+                Node::Operator(x, _) => write!(f, "<mo>{}&#x0338;</mo>", escape(&x.to_string())),
+                // 結合文字で打ち消せないものは斜線を重ねる
+                n => write!(f, r#"<menclose notation="updiagonalstrike">{}</menclose>"#, n),
             },
+            Node::HDotsFor(_) => write!(f, "<mo>…</mo>"),
             Node::Matrix(content, columnalign) => {
-                let mut mathml = format!("<mtable{}><mtr><mtd>", columnalign);
-                for (i, node) in content.iter().enumerate() {
+                let mut mathml = format!("<mtable{}>", columnalign);
+                // 現在の行の各セルの属性と中身
+                let mut row = vec![(String::new(), String::new())];
+                for node in content.iter() {
                     match node {
-                        Node::NewLine => {
-                            mathml.push_str("</mtd></mtr>");
-                            if i < content.len() {
-                                mathml.push_str("<mtr><mtd>")
-                            }
+                        Node::NewLine(gap) => {
+                            write_row(&mut mathml, &row, *gap);
+                            row = vec![(String::new(), String::new())];
                         },
-                        Node::Ampersand => {
-                            mathml.push_str("</mtd>");
-                            if i < content.len() {
-                                mathml.push_str("<mtd>")
+                        Node::Ampersand => row.push((String::new(), String::new())),
+                        node => {
+                            let (attrs, cell) = row.last_mut().unwrap();
+                            // `\hdotsfor{n}` のセルは n 列にまたがる
+                            if let Node::HDotsFor(n) = node {
+                                *attrs = format!(r#" columnspan="{}""#, n);
                             }
+                            cell.push_str(&node.to_string());
                         },
-                        node => { mathml = format!("{}{}", mathml, node); },
                     }
                 }
-                mathml.push_str("</mtd></mtr></mtable>");
+                write_row(&mut mathml, &row, None);
+                mathml.push_str("</mtable>");
                 
                 write!(f, "{}", mathml)
             },
-            Node::Text(text) => write!(f, "<mtext>{}</mtext>", text),
-            Node::Style(display, content) => match display {
-                Some(DisplayStyle::Block)  => write!(f, r#"<mstyle displaystyle="true">{}</mstyle>"#, content),
-                Some(DisplayStyle::Inline) => write!(f, r#"<mstyle displaystyle="false">{}</mstyle>"#, content),
-                None => write!(f, "<mstyle>{}</mstyle>", content),
+            Node::Color(color, content) => write!(f, r#"<mstyle mathcolor="{}">{}</mstyle>"#, escape(color), content),
+            Node::Enclose{notation, attrs, content} => {
+                write!(f, r#"<menclose notation="{}""#, escape(notation))?;
+                write_attrs(f, attrs)?;
+                write!(f, ">{}</menclose>", content)
+            },
+            Node::Padded{attrs, content} => {
+                write!(f, "<mpadded")?;
+                write_attrs(f, attrs)?;
+                write!(f, ">{}</mpadded>", content)
+            },
+            Node::Html{attrs, content} => {
+                write!(f, "<mrow")?;
+                write_attrs(f, attrs)?;
+                write!(f, ">{}</mrow>", content)
+            },
+            Node::Phantom(content) => write!(f, "<mphantom>{}</mphantom>", content),
+            Node::Atom{class, movablelimits, content} => match content.operator_text() {
+                // 演算子として書けるものは `<mo>` の属性で分類する
+                Some(op) => {
+                    write!(f, "<mo")?;
+                    if let Some(form) = class.form() {
+                        write!(f, r#" form="{}""#, form)?;
+                    }
+                    write!(f, r#" lspace="{}" rspace="{}""#, class.lspace(), class.rspace())?;
+                    if let Some(movablelimits) = movablelimits {
+                        write!(f, r#" movablelimits="{}""#, movablelimits)?;
+                    }
+                    write!(f, ">{}</mo>", op)
+                },
+                // それ以外は前後に空白を入れる
+                None => {
+                    write!(f, "<mrow>")?;
+                    if class.lspace().value != 0. {
+                        write!(f, r#"<mspace width="{}"/>"#, class.lspace())?;
+                    }
+                    write!(f, "{}", content)?;
+                    if class.rspace().value != 0. {
+                        write!(f, r#"<mspace width="{}"/>"#, class.rspace())?;
+                    }
+                    write!(f, "</mrow>")
+                },
+            },
+            Node::Text(text, var) => match var {
+                Variant::Normal => write!(f, "<mtext>{}</mtext>", escape(text)),
+                var             => write!(f, r#"<mtext mathvariant="{}">{}</mtext>"#, var, escape(text)),
+            },
+            Node::Style(display, scriptlevel, content) => {
+                write!(f, "<mstyle")?;
+                match display {
+                    Some(DisplayStyle::Block)  => write!(f, r#" displaystyle="true""#)?,
+                    Some(DisplayStyle::Inline) => write!(f, r#" displaystyle="false""#)?,
+                    None => (),
+                }
+                if let Some(level) = scriptlevel {
+                    write!(f, r#" scriptlevel="{}""#, level)?;
+                }
+                write!(f, ">{}</mstyle>", content)
             },
             node => write!(f, "<mtext>[PARSE ERROR: {:?}]</mtext>", node),
         }
     }
 }
 
+impl Node {
+    /// 一つの `<mo>` の中身として書ける場合はその文字列を返す.
+    pub(crate) fn operator_text(&self) -> Option<String> {
+        match self {
+            Node::Operator(op, Variant::Normal) => Some(escape(&op.to_string())),
+            Node::OtherOperator(op) => Some(op.to_string()),
+            Node::Letter(x, Variant::Normal) => Some(x.to_string()),
+            Node::Function(fun, Variant::Normal, None) => Some(fun.clone()),
+            Node::Row(nodes) if !nodes.is_empty() => nodes.iter().map(Node::operator_text).collect(),
+            _ => None,
+        }
+    }
+}
+
+/// 行列の一行を書き出す.
+/// 
+/// `\\[4pt]` の行間は行のすべてのセルの深さを増やして確保する.
+fn write_row(mathml: &mut String, row: &[(String, String)], gap: Option<Length>) {
+    mathml.push_str("<mtr>");
+    for (attrs, cell) in row {
+        match gap {
+            Some(gap) => mathml.push_str(&format!(r#"<mtd{}><mpadded depth="+{}">{}</mpadded></mtd>"#, attrs, gap, cell)),
+            None => mathml.push_str(&format!("<mtd{}>{}</mtd>", attrs, cell)),
+        }
+    }
+    mathml.push_str("</mtr>");
+}
+
+fn write_attrs<N: fmt::Display>(f: &mut fmt::Formatter<'_>, attrs: &[(N, String)]) -> fmt::Result {
+    for (name, value) in attrs {
+        write!(f, r#" {}="{}""#, name, escape(value))?;
+    }
+    Ok(())
+}
+
+/// Escape special characters of XML.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c   => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::attribute::Variant;
@@ -120,13 +251,14 @@ mod tests {
 
     #[test]
     fn node_display() {
-        let problems = vec![
-            (Node::Number("3.14".to_owned()), "<mn>3.14</mn>"),
+        let problems = [
+            (Node::Number("3.14".to_owned(), Variant::Normal), "<mn>3.14</mn>"),
+            (Node::Number("1".to_owned(), Variant::Bold), r#"<mn mathvariant="bold">1</mn>"#),
             (Node::Letter('x', Variant::Italic), "<mi>x</mi>"),
             (Node::Letter('α', Variant::Italic), "<mi>α</mi>"),
             (Node::Letter('あ', Variant::Normal), r#"<mi mathvariant="normal">あ</mi>"#),
             (
-                Node::Row(vec![ Node::Operator('+'), Node::Operator('-') ]), 
+                Node::Row(vec![ Node::Operator('+', Variant::Normal), Node::Operator('-', Variant::Normal) ]), 
                 r"<mrow><mo>+</mo><mo>-</mo></mrow>"
             ),
         ];
@@ -1,4 +1,5 @@
 use std::fmt;
+use crate::DisplayStyle;
 
 /// mi mathvariant attribute
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -55,12 +56,29 @@ impl fmt::Display for Accent {
     }
 }
 
+/// mo stretchy attribute
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stretchy {
+    True,
+    False,
+}
+
+impl fmt::Display for Stretchy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stretchy::True => write!(f, "true"),
+            Stretchy::False => write!(f, "false"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LineThickness {
     Thin,
     Medium,
     Thick,
     Length(u8),
+    Dimension(Length),
 }
 impl fmt::Display for LineThickness {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -69,6 +87,27 @@ impl fmt::Display for LineThickness {
             LineThickness::Medium    => write!(f, r#""#),
             LineThickness::Thick     => write!(f, r#" linethickness="medium""#),
             LineThickness::Length(l) => write!(f, r#" linethickness="{}""#, l),
+            LineThickness::Dimension(d) => write!(f, r#" linethickness="{}""#, d),
+        }
+    }
+}
+
+/// mfrac numalign and bevelled attributes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FracAttr {
+    Normal,
+    NumAlignLeft,
+    NumAlignRight,
+    Bevelled,
+}
+
+impl fmt::Display for FracAttr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FracAttr::Normal        => write!(f, r#""#),
+            FracAttr::NumAlignLeft  => write!(f, r#" numalign="left""#),
+            FracAttr::NumAlignRight => write!(f, r#" numalign="right""#),
+            FracAttr::Bevelled      => write!(f, r#" bevelled="true""#),
         }
     }
 }
@@ -89,3 +128,235 @@ impl fmt::Display for ColumnAlign {
         }
     }
 }
+
+/// TeX math style
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathStyle {
+    Display,
+    Text,
+    Script,
+    ScriptScript,
+}
+
+impl MathStyle {
+    /// style of the numerator and the denominator of a fraction
+    pub fn fraction(self) -> MathStyle {
+        match self {
+            MathStyle::Display => MathStyle::Text,
+            MathStyle::Text    => MathStyle::Script,
+            _                  => MathStyle::ScriptScript,
+        }
+    }
+
+    /// style of sub/superscripts
+    pub fn script(self) -> MathStyle {
+        match self {
+            MathStyle::Display | MathStyle::Text => MathStyle::Script,
+            _                                    => MathStyle::ScriptScript,
+        }
+    }
+
+    pub fn display(self) -> DisplayStyle {
+        match self {
+            MathStyle::Display => DisplayStyle::Block,
+            _                  => DisplayStyle::Inline,
+        }
+    }
+
+    pub fn scriptlevel(self) -> u8 {
+        match self {
+            MathStyle::Display | MathStyle::Text => 0,
+            MathStyle::Script                    => 1,
+            MathStyle::ScriptScript              => 2,
+        }
+    }
+}
+
+impl From<DisplayStyle> for MathStyle {
+    fn from(display: DisplayStyle) -> MathStyle {
+        match display {
+            DisplayStyle::Block  => MathStyle::Display,
+            DisplayStyle::Inline => MathStyle::Text,
+        }
+    }
+}
+
+/// TeX math class of an atom
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathClass {
+    Ord,
+    Op,
+    Bin,
+    Rel,
+    Open,
+    Close,
+    Punct,
+    Inner,
+}
+
+impl MathClass {
+    /// `form` attribute of `<mo>`
+    pub fn form(self) -> Option<&'static str> {
+        match self {
+            MathClass::Op | MathClass::Open         => Some("prefix"),
+            MathClass::Close                        => Some("postfix"),
+            MathClass::Bin | MathClass::Rel | MathClass::Punct => Some("infix"),
+            MathClass::Ord | MathClass::Inner       => None,
+        }
+    }
+
+    /// space before the atom
+    pub fn lspace(self) -> Length {
+        match self {
+            MathClass::Op | MathClass::Inner => Length::mu(3.),
+            MathClass::Bin                   => Length::mu(4.),
+            MathClass::Rel                   => Length::mu(5.),
+            _                                => Length::em(0.),
+        }
+    }
+
+    /// space after the atom
+    pub fn rspace(self) -> Length {
+        match self {
+            MathClass::Punct => Length::mu(3.),
+            class            => class.lspace(),
+        }
+    }
+}
+
+/// CSS length unit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthUnit {
+    Em,
+    Ex,
+    Pt,
+    Px,
+    In,
+    Cm,
+    Mm,
+}
+
+impl fmt::Display for LengthUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LengthUnit::Em => write!(f, "em"),
+            LengthUnit::Ex => write!(f, "ex"),
+            LengthUnit::Pt => write!(f, "pt"),
+            LengthUnit::Px => write!(f, "px"),
+            LengthUnit::In => write!(f, "in"),
+            LengthUnit::Cm => write!(f, "cm"),
+            LengthUnit::Mm => write!(f, "mm"),
+        }
+    }
+}
+
+/// length in a CSS unit
+/// 
+/// TeX dimensions are converted into CSS lengths by [`Length::parse`](#method.parse).
+/// The value is printed with at most four decimal places, e.g. `0.1667em`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Length {
+    pub value: f32,
+    pub unit: LengthUnit,
+}
+
+impl Length {
+    pub const fn new(value: f32, unit: LengthUnit) -> Length {
+        Length { value, unit }
+    }
+
+    pub const fn em(value: f32) -> Length {
+        Length::new(value, LengthUnit::Em)
+    }
+
+    /// math unit: 18 mu = 1 em
+    pub fn mu(value: f32) -> Length {
+        Length::em(value / 18.)
+    }
+
+    /// Parse a TeX dimension such as `2mm`, `-1pt`, `3 mu` or `.5em`.
+    /// 
+    /// TeX points are treated as CSS points. `mu` is converted to `em`,
+    /// and `bp`, `pc`, `dd`, `cc` and `sp` are converted to `pt`.
+    pub fn parse(text: &str) -> Option<Length> {
+        let text = text.trim();
+        let i = text.find(|c: char| c.is_ascii_alphabetic())?;
+        let (value, unit) = text.split_at(i);
+
+        // 符号は複数並んでもよい
+        let mut sign = 1.;
+        let mut value = value.trim_start();
+        while let Some(c) = value.chars().next().filter(|c| *c == '+' || *c == '-') {
+            if c == '-' { sign = -sign; }
+            value = value[1..].trim_start();
+        }
+        let value = value.trim().replace(',', ".");
+        let value: f32 = if value.is_empty() || value == "." {
+            return None;
+        } else {
+            sign * value.parse::<f32>().ok()?
+        };
+
+        let unit = unit.trim();
+        let unit = unit.strip_prefix("true").unwrap_or(unit);
+        let length = match unit {
+            "em" => Length::new(value, LengthUnit::Em),
+            "ex" => Length::new(value, LengthUnit::Ex),
+            "pt" => Length::new(value, LengthUnit::Pt),
+            "px" => Length::new(value, LengthUnit::Px),
+            "in" => Length::new(value, LengthUnit::In),
+            "cm" => Length::new(value, LengthUnit::Cm),
+            "mm" => Length::new(value, LengthUnit::Mm),
+            "mu" => Length::mu(value),
+            "bp" => Length::new(value, LengthUnit::Pt),
+            "pc" => Length::new(12. * value, LengthUnit::Pt),
+            "dd" => Length::new(1238. / 1157. * value, LengthUnit::Pt),
+            "cc" => Length::new(12. * 1238. / 1157. * value, LengthUnit::Pt),
+            "sp" => Length::new(value / 65536., LengthUnit::Pt),
+            _ => { return None; },
+        };
+        Some(length)
+    }
+
+    pub fn scale(self, factor: f32) -> Length {
+        Length::new(factor * self.value, self.unit)
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = format!("{:.4}", self.value);
+        let value = value.trim_end_matches('0').trim_end_matches('.');
+        let value = if value == "-0" { "0" } else { value };
+        write!(f, "{}{}", value, self.unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Length, LengthUnit};
+
+    #[test]
+    fn length_test() {
+        let problems = [
+            ("2mm",   Some("2mm")),
+            ("-1pt",  Some("-1pt")),
+            ("3mu",   Some("0.1667em")),
+            ("18 mu", Some("1em")),
+            (".5em",  Some("0.5em")),
+            ("- -2ex", Some("2ex")),
+            ("1,5cm", Some("1.5cm")),
+            ("1pc",   Some("12pt")),
+            ("65536sp", Some("1pt")),
+            ("2truein", Some("2in")),
+            ("1",     None),
+            ("pt",    None),
+            ("1 foo", None),
+        ];
+        for (problem, answer) in problems.iter() {
+            assert_eq!(Length::parse(problem).map(|l| l.to_string()).as_deref(), *answer);
+        }
+        assert_eq!(Length::em(3./18.).to_string(), "0.1667em");
+        assert_eq!(Length::new(-0.00001, LengthUnit::Em).to_string(), "0em");
+    }
+}
@@ -0,0 +1,242 @@
+//! Color
+//!
+//! xcolor の色指定を CSS の色に変換する.
+//!
+
+/// RGB 色 (各成分は 0 以上 1 以下)
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rgb(f32, f32, f32);
+
+impl Rgb {
+    const WHITE: Rgb = Rgb(1., 1., 1.);
+
+    fn from_hex(hex: u32) -> Rgb {
+        Rgb(
+            ((hex >> 16) & 0xff) as f32 / 255.,
+            ((hex >>  8) & 0xff) as f32 / 255.,
+            ( hex        & 0xff) as f32 / 255.,
+        )
+    }
+
+    /// `self` を `percent`%, `other` を残りの割合で混ぜる.
+    fn mix(self, percent: f32, other: Rgb) -> Rgb {
+        let p = (percent / 100.).clamp(0., 1.);
+        Rgb(
+            p * self.0 + (1. - p) * other.0,
+            p * self.1 + (1. - p) * other.1,
+            p * self.2 + (1. - p) * other.2,
+        )
+    }
+
+    fn complement(self) -> Rgb {
+        Rgb(1. - self.0, 1. - self.1, 1. - self.2)
+    }
+
+    fn to_css(self) -> String {
+        let byte = |x: f32| (x.clamp(0., 1.) * 255.).round() as u8;
+        format!("#{:02x}{:02x}{:02x}", byte(self.0), byte(self.1), byte(self.2))
+    }
+}
+
+/// xcolor の色指定を CSS の色に変換する.
+///
+/// `model` は `\color[rgb]{0.1,0.2,0.3}` の `rgb` のような色モデルである.
+/// 色モデルが指定されていない場合, `spec` は `red`, `red!50!blue` のような色の名前か色の式である.
+///
+/// ```text
+/// parse_color(None, "red")                  => Some("#ff0000")
+/// parse_color(None, "red!50!blue")          => Some("#800080")
+/// parse_color(Some("HTML"), "FF8800")       => Some("#ff8800")
+/// parse_color(Some("rgb"), "0.1,0.2,0.3")   => Some("#1a334d")
+/// ```
+pub(crate) fn parse_color(model: Option<&str>, spec: &str) -> Option<String> {
+    let spec = spec.trim();
+    let rgb = match model.map(str::trim) {
+        None => {
+            if let Some(rgb) = parse_expression(spec) {
+                rgb
+            } else if !spec.is_empty() && spec.chars().all(|c| c.is_ascii_alphabetic()) {
+                // xcolor にない名前は CSS の色の名前とみなす
+                return Some(spec.to_ascii_lowercase());
+            } else {
+                return None;
+            }
+        },
+        Some(model) => parse_model(model, spec)?,
+    };
+    Some(rgb.to_css())
+}
+
+fn parse_model(model: &str, spec: &str) -> Option<Rgb> {
+    let values = || spec.split(',').map(|x| x.trim().parse::<f32>().ok()).collect::<Option<Vec<f32>>>();
+    match model {
+        "rgb" => match values()?.as_slice() {
+            &[r, g, b] => Some(Rgb(r, g, b)),
+            _ => None,
+        },
+        "RGB" => match values()?.as_slice() {
+            &[r, g, b] => Some(Rgb(r / 255., g / 255., b / 255.)),
+            _ => None,
+        },
+        "HTML" => {
+            if spec.len() != 6 { return None; }
+            u32::from_str_radix(spec, 16).ok().map(Rgb::from_hex)
+        },
+        "gray" => match values()?.as_slice() {
+            &[x] => Some(Rgb(x, x, x)),
+            _ => None,
+        },
+        "cmyk" => match values()?.as_slice() {
+            &[c, m, y, k] => Some(Rgb((1. - c) * (1. - k), (1. - m) * (1. - k), (1. - y) * (1. - k))),
+            _ => None,
+        },
+        "cmy" => match values()?.as_slice() {
+            &[c, m, y] => Some(Rgb(1. - c, 1. - m, 1. - y)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `red!50!blue!30` のような色の式を評価する.
+fn parse_expression(spec: &str) -> Option<Rgb> {
+    let (complement, spec) = match spec.strip_prefix('-') {
+        Some(spec) => (true, spec),
+        None       => (false, spec),
+    };
+    let mut parts = spec.split('!');
+    let mut rgb = named_color(parts.next()?.trim())?;
+    while let Some(percent) = parts.next() {
+        let percent = percent.trim().parse::<f32>().ok()?;
+        let other = match parts.next() {
+            Some(name) => named_color(name.trim())?,
+            None       => Rgb::WHITE,
+        };
+        rgb = rgb.mix(percent, other);
+    }
+    Some(if complement { rgb.complement() } else { rgb })
+}
+
+fn named_color(name: &str) -> Option<Rgb> {
+    let rgb = match name {
+        // xcolor の基本色
+        "red"       => Rgb(1., 0., 0.),
+        "green"     => Rgb(0., 1., 0.),
+        "blue"      => Rgb(0., 0., 1.),
+        "cyan"      => Rgb(0., 1., 1.),
+        "magenta"   => Rgb(1., 0., 1.),
+        "yellow"    => Rgb(1., 1., 0.),
+        "black"     => Rgb(0., 0., 0.),
+        "white"     => Rgb(1., 1., 1.),
+        "gray"      => Rgb(0.5, 0.5, 0.5),
+        "darkgray"  => Rgb(0.25, 0.25, 0.25),
+        "lightgray" => Rgb(0.75, 0.75, 0.75),
+        "brown"     => Rgb(0.75, 0.5, 0.25),
+        "lime"      => Rgb(0.75, 1., 0.),
+        "olive"     => Rgb(0.5, 0.5, 0.),
+        "orange"    => Rgb(1., 0.5, 0.),
+        "pink"      => Rgb(1., 0.75, 0.75),
+        "purple"    => Rgb(0.75, 0., 0.25),
+        "teal"      => Rgb(0., 0.5, 0.5),
+        "violet"    => Rgb(0.5, 0., 0.5),
+        // dvipsnames
+        "Apricot"        => Rgb::from_hex(0xFBB982),
+        "Aquamarine"     => Rgb::from_hex(0x00B5BE),
+        "Bittersweet"    => Rgb::from_hex(0xC04F17),
+        "Black"          => Rgb::from_hex(0x221E1F),
+        "Blue"           => Rgb::from_hex(0x2D2F92),
+        "BlueGreen"      => Rgb::from_hex(0x00B3B8),
+        "BlueViolet"     => Rgb::from_hex(0x473992),
+        "BrickRed"       => Rgb::from_hex(0xB6321C),
+        "Brown"          => Rgb::from_hex(0x792500),
+        "BurntOrange"    => Rgb::from_hex(0xF7921D),
+        "CadetBlue"      => Rgb::from_hex(0x74729A),
+        "CarnationPink"  => Rgb::from_hex(0xF282B4),
+        "Cerulean"       => Rgb::from_hex(0x00A2E3),
+        "CornflowerBlue" => Rgb::from_hex(0x41B0E4),
+        "Cyan"           => Rgb::from_hex(0x00AEEF),
+        "Dandelion"      => Rgb::from_hex(0xFDBC42),
+        "DarkOrchid"     => Rgb::from_hex(0xA4538A),
+        "Emerald"        => Rgb::from_hex(0x00A99D),
+        "ForestGreen"    => Rgb::from_hex(0x009B55),
+        "Fuchsia"        => Rgb::from_hex(0x8C368C),
+        "Goldenrod"      => Rgb::from_hex(0xFFDF42),
+        "Gray"           => Rgb::from_hex(0x949698),
+        "Green"          => Rgb::from_hex(0x00A64F),
+        "GreenYellow"    => Rgb::from_hex(0xDFE674),
+        "JungleGreen"    => Rgb::from_hex(0x00A99A),
+        "Lavender"       => Rgb::from_hex(0xF49EC4),
+        "LimeGreen"      => Rgb::from_hex(0x8DC73E),
+        "Magenta"        => Rgb::from_hex(0xEC008C),
+        "Mahogany"       => Rgb::from_hex(0xA9341F),
+        "Maroon"         => Rgb::from_hex(0xAF3235),
+        "Melon"          => Rgb::from_hex(0xF89E7B),
+        "MidnightBlue"   => Rgb::from_hex(0x006795),
+        "Mulberry"       => Rgb::from_hex(0xA93C93),
+        "NavyBlue"       => Rgb::from_hex(0x006EB8),
+        "OliveGreen"     => Rgb::from_hex(0x3C8031),
+        "Orange"         => Rgb::from_hex(0xF58137),
+        "OrangeRed"      => Rgb::from_hex(0xED135A),
+        "Orchid"         => Rgb::from_hex(0xAF72B0),
+        "Peach"          => Rgb::from_hex(0xF7965A),
+        "Periwinkle"     => Rgb::from_hex(0x7977B8),
+        "PineGreen"      => Rgb::from_hex(0x008B72),
+        "Plum"           => Rgb::from_hex(0x92268F),
+        "ProcessBlue"    => Rgb::from_hex(0x00B0F0),
+        "Purple"         => Rgb::from_hex(0x99479B),
+        "RawSienna"      => Rgb::from_hex(0x974006),
+        "Red"            => Rgb::from_hex(0xED1B23),
+        "RedOrange"      => Rgb::from_hex(0xF26035),
+        "RedViolet"      => Rgb::from_hex(0xA1246B),
+        "Rhodamine"      => Rgb::from_hex(0xEF559F),
+        "RoyalBlue"      => Rgb::from_hex(0x0071BC),
+        "RoyalPurple"    => Rgb::from_hex(0x613F99),
+        "RubineRed"      => Rgb::from_hex(0xED017D),
+        "Salmon"         => Rgb::from_hex(0xF69289),
+        "SeaGreen"       => Rgb::from_hex(0x3FBC9D),
+        "Sepia"          => Rgb::from_hex(0x671800),
+        "SkyBlue"        => Rgb::from_hex(0x46C5DD),
+        "SpringGreen"    => Rgb::from_hex(0xC6DC67),
+        "Tan"            => Rgb::from_hex(0xDA9D76),
+        "TealBlue"       => Rgb::from_hex(0x00AEB3),
+        "Thistle"        => Rgb::from_hex(0xD883B7),
+        "Turquoise"      => Rgb::from_hex(0x00B4CE),
+        "Violet"         => Rgb::from_hex(0x58429B),
+        "VioletRed"      => Rgb::from_hex(0xEF58A0),
+        "White"          => Rgb::from_hex(0xFFFFFF),
+        "WildStrawberry" => Rgb::from_hex(0xEE2967),
+        "Yellow"         => Rgb::from_hex(0xFFF200),
+        "YellowGreen"    => Rgb::from_hex(0x98CC70),
+        "YellowOrange"   => Rgb::from_hex(0xFAA21A),
+        _ => { return None; },
+    };
+    Some(rgb)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_color;
+
+    #[test]
+    fn color_test() {
+        let problems = [
+            (None, "red", Some("#ff0000")),
+            (None, "red!50!blue", Some("#800080")),
+            (None, "red!50", Some("#ff8080")),
+            (None, "-red", Some("#00ffff")),
+            (None, "ForestGreen", Some("#009b55")),
+            (None, "navy", Some("navy")),
+            (None, "red!x", None),
+            (Some("HTML"), "FF8800", Some("#ff8800")),
+            (Some("rgb"), "0.1,0.2,0.3", Some("#1a334d")),
+            (Some("RGB"), "255, 128, 0", Some("#ff8000")),
+            (Some("gray"), "0.5", Some("#808080")),
+            (Some("cmyk"), "0,1,1,0", Some("#ff0000")),
+            (Some("rgb"), "0.1,0.2", None),
+        ];
+
+        for (model, spec, answer) in problems.iter() {
+            assert_eq!(parse_color(*model, spec).as_deref(), *answer);
+        }
+    }
+}
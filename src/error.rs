@@ -10,6 +10,10 @@ pub enum LatexError {
         location: Token, got: Token,
     },
     UnknownEnvironment(String),
+    UnknownColor(String),
+    AmbiguousFraction(Token),
+    InvalidArgument(Token, String),
+    OutsideMatrix(Token),
     InvalidNumberOfDollarSigns,
 }
 
@@ -27,6 +31,18 @@ impl fmt::Display for LatexError {
             LatexError::UnknownEnvironment(environment) => write!(f,
                 "An unknown environment \"{}\" is found", environment
             ),
+            LatexError::UnknownColor(color) => write!(f,
+                "An unknown color \"{}\" is found", color
+            ),
+            LatexError::AmbiguousFraction(token) => write!(f,
+                "The fraction \"{:?}\" is ambiguous. Enclose each fraction in braces.", token
+            ),
+            LatexError::InvalidArgument(token, arg) => write!(f,
+                "The argument \"{}\" of \"{:?}\" is invalid.", arg, token
+            ),
+            LatexError::OutsideMatrix(token) => write!(f,
+                "The token \"{:?}\" must be placed directly in a matrix cell.", token
+            ),
             LatexError::InvalidNumberOfDollarSigns => write!(f,
                 "The number of dollar sings found is invalid."
             ),
@@ -5,8 +5,9 @@
 //! 
 
 use super::{
-    token::Token, 
-    attribute::Variant,
+    token::{Token, TextToken}, 
+    attribute::{Variant, Length},
+    NumberFormat, DecimalSeparator,
 };
 
 /// Lexer
@@ -15,15 +16,23 @@ pub(crate) struct Lexer<'a> {
     input: std::str::Chars<'a>,
     pub(crate) cur: char,
     pub(crate) peek: char,
+    /// 数値の書式
+    pub(crate) number: NumberFormat,
 }
 
 impl<'a> Lexer<'a> {
     /// 入力ソースコードを受け取り Lexer インスタンスを生成する.
     pub(crate) fn new(input: &'a str) -> Self {
+        Lexer::with_number_format(input, NumberFormat::default())
+    }
+
+    /// 数値の書式を指定して Lexer インスタンスを生成する.
+    pub(crate) fn with_number_format(input: &'a str, number: NumberFormat) -> Self {
         let mut lexer = Lexer { 
             input: input.chars(),
             cur:  '\u{0}',
             peek: '\u{0}',
+            number,
         };
         lexer.read_char();
         lexer.read_char();
@@ -58,23 +67,388 @@ impl<'a> Lexer<'a> {
             command.push(self.read_char());
         }
 
-        Token::from_command(&command)
+        match Token::from_command(&command) {
+            Token::Kern => self.read_kern(command),
+            Token::CharCode => self.read_char_code(command),
+            token => token,
+        }
+    }
+
+    /// `\kern-1pt` や `\hspace{2mm}` の長さを読み込みトークンに変換する.
+    ///
+    /// 長さとして読めない場合は何も読み込まずに `Token::Command` を返す.
+    fn read_kern(&mut self, command: String) -> Token {
+        let mut lexer = self.clone();
+        match lexer.read_dimension() {
+            Some(length) => {
+                *self = lexer;
+                Token::Space(length)
+            },
+            None => Token::Command(command),
+        }
+    }
+
+    /// `\hspace*{2mm plus 1mm}` や `-1pt` の長さを読み込む. 伸縮量 (`plus`, `minus`) は無視する.
+    fn read_dimension(&mut self) -> Option<Length> {
+        self.skip_whitespace();
+        // `\hspace*` の `*` は読み飛ばす
+        if self.cur == '*' {
+            self.read_char();
+            self.skip_whitespace();
+        }
+
+        if self.cur == '{' {
+            self.read_char();
+            let dimension = self.read_raw_group('{', '}')?;
+            let end = ["plus", "minus"].iter()
+                .filter_map(|glue| dimension.find(glue))
+                .min()
+                .unwrap_or(dimension.len());
+            return Length::parse(&dimension[..end]);
+        }
+
+        let length = Length::parse(&self.read_unbraced_dimension())?;
+        for glue in ["plus", "minus"] {
+            let mut lexer = self.clone();
+            lexer.skip_whitespace();
+            if glue.chars().all(|c| lexer.read_char() == c) {
+                let stretch = lexer.read_unbraced_dimension();
+                // `fil`, `fill`, `filll` も伸縮量の単位として読める
+                if Length::parse(&stretch).is_some() || stretch.trim_end_matches('l').ends_with("fi") {
+                    *self = lexer;
+                }
+            }
+        }
+        Some(length)
+    }
+
+    /// `-1.5pt` や `2truein` のような括弧で囲まれていない長さを文字列として読み込む.
+    fn read_unbraced_dimension(&mut self) -> String {
+        let mut dimension = String::new();
+        while matches!(self.cur, '+' | '-' | '.' | ',' | ' ') || self.cur.is_ascii_digit() {
+            dimension.push(self.read_char());
+        }
+        // `2truein` の `true` は読み飛ばす
+        let mut lexer = self.clone();
+        if "true".chars().all(|c| lexer.read_char() == c) {
+            *self = lexer;
+        }
+        // 単位は 2 文字 (`fil`, `fill`, `filll` だけは 3 文字以上)
+        for _ in 0..2 {
+            if self.cur.is_ascii_alphabetic() {
+                dimension.push(self.read_char());
+            }
+        }
+        if dimension.ends_with("fi") {
+            while self.cur == 'l' {
+                dimension.push(self.read_char());
+            }
+        }
+        dimension
     }
     
+    /// `\unicode{x1D49C}`, `\char"2A`, `\symbol{98}` の文字コードを読み込みトークンに変換する.
+    fn read_char_code(&mut self, command: String) -> Token {
+        self.skip_whitespace();
+        let code = if command == "char" {
+            self.read_tex_number()
+        } else {
+            // `\unicode[.8,0]{x22D6}` の省略可能引数は読み飛ばす
+            if command == "unicode" && self.cur == '[' {
+                self.read_char();
+                if self.read_raw_group('[', ']').is_none() { return Token::Command(command); }
+                self.skip_whitespace();
+            }
+            if self.cur != '{' { return Token::Command(command); }
+            self.read_char();
+            match self.read_raw_group('{', '}') {
+                Some(arg) => {
+                    let arg = arg.trim();
+                    if command == "unicode" {
+                        match arg.strip_prefix(|c| c == 'x' || c == 'X') {
+                            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                            None      => arg.parse::<u32>().ok(),
+                        }
+                    } else {
+                        Lexer::new(arg).read_tex_number()
+                    }
+                },
+                None => None,
+            }
+        };
+
+        // XML の文字として使えないコードポイントは無視する
+        match code.and_then(char::from_u32).filter(|&c| is_xml_char(c)) {
+            Some(c) => Token::from_char(c),
+            None => Token::Command(command),
+        }
+    }
+
+    /// TeX の数値 (`98`, `"2A`, `'142`, `` `b ``) を読み込む.
+    fn read_tex_number(&mut self) -> Option<u32> {
+        let radix = match self.cur {
+            '"'  => { self.read_char(); 16 },
+            '\'' => { self.read_char(); 8 },
+            '`'  => {
+                self.read_char();
+                // `` `\b `` のように 1 文字のコマンドで指定してもよい
+                if self.cur == '\\' { self.read_char(); }
+                return match self.read_char() {
+                    '\u{0}' => None,
+                    c => Some(c as u32),
+                };
+            },
+            _    => 10,
+        };
+        let mut digits = String::new();
+        while self.cur.is_digit(radix) {
+            digits.push(self.read_char());
+        }
+        u32::from_str_radix(&digits, radix).ok()
+    }
+
     /// 数字一つ分を読み込みトークンに変換する.
+    ///
+    /// 小数点と桁区切り (`{,}`, `\,`, 細いスペース) は直後に数字が続く場合だけ数値の一部とみなす.
     fn read_number(&mut self) -> Token {
+        let NumberFormat { decimal_separator, grouping, exponent } = self.number;
+        let (decimal_separator, comma) = match decimal_separator {
+            DecimalSeparator::Period => ('.', false),
+            DecimalSeparator::Comma  => (',', true),
+        };
         let mut number = String::new();
-        let mut has_period = false;
-        while self.cur.is_ascii_digit() || (self.cur == '.' && !has_period) {
-            if self.cur == '.' { has_period = true; }
-            number.push(self.read_char());
+        let mut has_decimal = false;
+        loop {
+            if self.cur.is_ascii_digit() {
+                number.push(self.read_char());
+            } else if !has_decimal && self.followed_by_digit(&[decimal_separator]) {
+                has_decimal = true;
+                number.push(self.read_char());
+            } else if self.followed_by_digit(&['{', ',', '}'])
+                && (comma && !has_decimal || grouping && !comma) {
+                // `3{,}14` や `1{,}000`
+                has_decimal |= comma;
+                self.skip_chars(3);
+                number.push(',');
+            } else if grouping && self.followed_by_digit(&['\\', ',']) {
+                self.skip_chars(2);
+                number.push('\u{2009}');
+            } else if grouping && matches!(self.cur, '\u{2009}' | '\u{202f}') && self.followed_by_digit(&[self.cur]) {
+                number.push(self.read_char());
+            } else {
+                break;
+            }
+        }
+        // `1.5e-3` の指数部
+        if exponent && matches!(self.cur, 'e' | 'E') {
+            let signed = matches!(self.peek, '+' | '-');
+            if self.followed_by_digit(&[self.cur, self.peek][..if signed { 2 } else { 1 }]) {
+                number.push(self.read_char());
+                if signed { number.push(self.read_char()); }
+                while self.cur.is_ascii_digit() {
+                    number.push(self.read_char());
+                }
+            }
         }
         Token::Number(number)
     }
 
+    /// 入力が `prefix` で始まり, その直後が数字であるかどうか.
+    fn followed_by_digit(&self, prefix: &[char]) -> bool {
+        let mut lexer = self.clone();
+        for &c in prefix {
+            if lexer.read_char() != c { return false; }
+        }
+        lexer.cur.is_ascii_digit()
+    }
+
+    /// `n` 文字読み飛ばす.
+    fn skip_chars(&mut self, n: usize) {
+        for _ in 0..n {
+            self.read_char();
+        }
+    }
+
+    /// 対応する閉じ括弧 `close` までの文字列をそのまま読み込む.
+    ///
+    /// 開き括弧は読み込み済みであることを仮定する. 閉じ括弧は読み飛ばす.
+    /// 閉じ括弧が見つからないまま入力が終了した場合は `None` を返す.
+    pub(crate) fn read_raw_group(&mut self, open: char, close: char) -> Option<String> {
+        self.read_group(open, close, true)
+    }
+
+    /// `\href` の URL のように, `%` をコメントとして扱わずに `{..}` の中身を読み込む.
+    pub(crate) fn read_verbatim_group(&mut self) -> Option<String> {
+        self.read_group('{', '}', false)
+    }
+
+    fn read_group(&mut self, open: char, close: char, comments: bool) -> Option<String> {
+        let mut text = String::new();
+        let mut depth = 0usize;
+        loop {
+            match self.cur {
+                '\u{0}' => { return None; },
+                '\\' => {
+                    text.push(self.read_char());
+                    if self.cur == '\u{0}' { return None; }
+                },
+                c if c == close => {
+                    if depth == 0 {
+                        self.read_char();
+                        return Some(text);
+                    }
+                    depth -= 1;
+                },
+                c if c == open => { depth += 1; },
+                '%' if comments => {
+                    // 引数の中のコメントも, 行末と次の行頭の空白まで読み飛ばす
+                    self.skip_comment();
+                    if self.cur == '\n' { self.read_char(); }
+                    while matches!(self.cur, ' ' | '\t') { self.read_char(); }
+                    continue;
+                },
+                _ => (),
+            }
+            text.push(self.read_char());
+        }
+    }
+
+    /// テキストモードで次のトークンを生成する.
+    ///
+    /// 空白は読み飛ばさず, 連続する空白は一つの `TextToken::Space` にまとめる.
+    pub(crate) fn next_text_token(&mut self) -> TextToken {
+        match self.cur {
+            '\u{0}' => TextToken::EOF,
+            ' ' | '\t' | '\n' | '\r' => {
+                self.skip_whitespace();
+                TextToken::Space
+            },
+            '{' => { self.read_char(); TextToken::LBrace },
+            '%' => {
+                self.skip_comment();
+                self.skip_whitespace();
+                self.next_text_token()
+            },
+            '~' => { self.read_char(); TextToken::Char('\u{a0}') },
+            '$' => {
+                self.read_char();
+                self.read_math('$')
+            },
+            '}' => { self.read_char(); TextToken::RBrace },
+            '\\' => {
+                self.read_char();
+                let first = self.read_char();
+                match first {
+                    '\u{0}' => TextToken::EOF,
+                    '{' | '}' | '$' | '%' | '&' | '#' | '_' => TextToken::Char(first),
+                    '(' => self.read_math(')'),
+                    ' ' | '\t' | '\n' | '\r' | '\\' => TextToken::Space,
+                    c if c.is_ascii_alphabetic() => {
+                        let mut command = first.to_string();
+                        while self.cur.is_ascii_alphabetic() {
+                            command.push(self.read_char());
+                        }
+                        // 英字のコマンドの直後の空白は読み飛ばす
+                        self.skip_whitespace();
+                        TextToken::Command(command)
+                    },
+                    c => TextToken::Command(c.to_string()),
+                }
+            },
+            // リガチャ
+            '-' => {
+                self.read_char();
+                if self.cur != '-' { return TextToken::Char('-'); }
+                self.read_char();
+                if self.cur != '-' { return TextToken::Char('–'); }
+                self.read_char();
+                TextToken::Char('—')
+            },
+            '`' | '\'' => {
+                let c = self.read_char();
+                if self.cur == c {
+                    self.read_char();
+                    TextToken::Char(if c == '`' { '“' } else { '”' })
+                } else {
+                    TextToken::Char(if c == '`' { '‘' } else { '’' })
+                }
+            },
+            '!' | '?' if self.peek == '`' => {
+                let c = self.read_char();
+                self.read_char();
+                TextToken::Char(if c == '!' { '¡' } else { '¿' })
+            },
+            _ => TextToken::Char(self.read_char()),
+        }
+    }
+
+    /// テキストモードでコマンドの引数を読み込む.
+    /// 
+    /// `{..}` で囲まれていない場合は 1 文字 (または一つのコマンド) だけを引数とする.
+    pub(crate) fn read_text_argument(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        match self.cur {
+            '\u{0}' => None,
+            '{' => {
+                self.read_char();
+                self.read_raw_group('{', '}')
+            },
+            '\\' => {
+                let mut command = self.read_char().to_string();
+                let first = self.read_char();
+                command.push(first);
+                while first.is_ascii_alphabetic() && self.cur.is_ascii_alphabetic() {
+                    command.push(self.read_char());
+                }
+                Some(command)
+            },
+            _ => Some(self.read_char().to_string()),
+        }
+    }
+
+    /// テキストモードの中の数式を閉じる `$` または `\)` まで読み込む.
+    /// 
+    /// 閉じられないまま入力が終了した場合は残りを全て数式とみなす.
+    fn read_math(&mut self, close: char) -> TextToken {
+        let mut math = String::new();
+        loop {
+            match self.cur {
+                '\u{0}' => break,
+                '$' if close == '$' => {
+                    self.read_char();
+                    break;
+                },
+                '\\' if self.peek == close && close != '$' => {
+                    self.read_char();
+                    self.read_char();
+                    break;
+                },
+                '\\' => {
+                    math.push(self.read_char());
+                    if self.cur != '\u{0}' {
+                        math.push(self.read_char());
+                    }
+                },
+                _ => math.push(self.read_char()),
+            }
+        }
+        TextToken::Math(math)
+    }
+
+    /// `%` から行末までのコメントを読み飛ばす.
+    fn skip_comment(&mut self) {
+        while self.cur != '\n' && self.cur != '\u{0}' {
+            self.read_char();
+        }
+    }
+
     /// 次のトークンを生成する.
     pub(crate) fn next_token(&mut self) -> Token {
         self.skip_whitespace();
+        while self.cur == '%' {
+            self.skip_comment();
+            self.skip_whitespace();
+        }
 
         let token = match self.cur {
             '=' => Token::Operator('='),
@@ -99,6 +473,8 @@ impl<'a> Lexer<'a> {
             '_' => Token::Underscore,
             '^' => Token::Circumflex,
             '&' => Token::Ampersand,
+            // 改行しない単語間の空白
+            '~' => Token::Space(Length::em(1./3.)),
             '\u{0}' => Token::EOF,
             ':' => if self.peek == '=' {
                 self.read_char();
@@ -111,7 +487,7 @@ impl<'a> Lexer<'a> {
                 } else if c.is_ascii_alphabetic() {
                     Token::Letter(c, Variant::Italic)
                 } else {
-                    Token::Letter(c, Variant::Normal)
+                    Token::from_char(c)
                 }
             },
         };
@@ -120,11 +496,16 @@ impl<'a> Lexer<'a> {
     }
 }
 
+/// XML 1.0 の `Char` に含まれる文字かどうか.
+fn is_xml_char(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r' | '\u{20}'..='\u{d7ff}' | '\u{e000}'..='\u{fffd}' | '\u{10000}'..)
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::{
-        token::Token, 
-        attribute::Variant
+        token::{Token, TextToken}, 
+        attribute::{Variant, Length, LengthUnit},
     };
     use super::*;
 
@@ -143,7 +524,31 @@ mod tests {
             ]),
             (r"\alpha\beta", vec![Token::Letter('α', Variant::Italic), Token::Letter('β', Variant::Italic)]),
             (r"x+y", vec![Token::Letter('x', Variant::Italic), Token::Operator('+'), Token::Letter('y', Variant::Italic)]),
-            (r"\ 1", vec![Token::Space(1.), Token::Number("1".to_owned())]),
+            (r"\ 1", vec![Token::Space(Length::em(1.)), Token::Number("1".to_owned())]),
+            (r"\kern-1pt x", vec![Token::Space(Length::new(-1., LengthUnit::Pt)), Token::Letter('x', Variant::Italic)]),
+            (r"\mkern 6mu", vec![Token::Space(Length::mu(6.))]),
+            (r"\hspace*{2mm}1", vec![Token::Space(Length::new(2., LengthUnit::Mm)), Token::Number("1".to_owned())]),
+            ("x % comment\n+ y", vec![Token::Letter('x', Variant::Italic), Token::Operator('+'), Token::Letter('y', Variant::Italic)]),
+            (r"50\%~x", vec![
+                Token::Number("50".to_owned()), Token::Letter('%', Variant::Normal),
+                Token::Space(Length::em(1./3.)), Token::Letter('x', Variant::Italic),
+            ]),
+            (r"\unicode{x1D49C}", vec![Token::Letter('𝒜', Variant::Italic)]),
+            (r"\unicode{8477}", vec![Token::Letter('ℝ', Variant::Italic)]),
+            (r"\char0", vec![Token::Command("char".to_owned())]),
+            (r"\unicode{x1B}", vec![Token::Command("unicode".to_owned())]),
+            (r"\unicode{xFFFE}", vec![Token::Command("unicode".to_owned())]),
+            (r"\unicode{x2A01}", vec![Token::BigOp('⨁')]),
+            (r#"\char"2A x"#, vec![Token::Operator('*'), Token::Letter('x', Variant::Italic)]),
+            (r"\char'142", vec![Token::Letter('b', Variant::Italic)]),
+            (r"\char`\%", vec![Token::Operator('%')]),
+            (r#"\symbol{"263A}"#, vec![Token::Symbol('☺')]),
+            (r"3.", vec![Token::Number("3".to_owned()), Token::Operator('.')]),
+            (r"1\,000", vec![Token::Number("1".to_owned()), Token::Space(Length::em(3./18.)), Token::Number("000".to_owned())]),
+            (r"1{,}000", vec![Token::Number("1".to_owned()), Token::LBrace]),
+            (r"1\,x", vec![Token::Number("1".to_owned()), Token::Space(Length::em(3./18.)), Token::Letter('x', Variant::Italic)]),
+            (r"2,5", vec![Token::Number("2".to_owned()), Token::Operator(','), Token::Number("5".to_owned())]),
+            (r"1e5", vec![Token::Number("1".to_owned()), Token::Letter('e', Variant::Italic)]),
         ];
 
         for (problem, answer) in problems.iter() {
@@ -153,4 +558,63 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn number_format_test() {
+        let comma = NumberFormat { decimal_separator: DecimalSeparator::Comma, ..NumberFormat::default() };
+        let exponent = NumberFormat { exponent: true, ..NumberFormat::default() };
+        let grouped = NumberFormat { grouping: true, ..NumberFormat::default() };
+        let problems = [
+            (comma, r"2,5", vec![Token::Number("2,5".to_owned())]),
+            (comma, r"3{,}14", vec![Token::Number("3,14".to_owned())]),
+            (comma, r"1\,000,5", vec![Token::Number("1".to_owned()), Token::Space(Length::em(3./18.))]),
+            (NumberFormat { grouping: true, ..comma }, r"1\,000,5", vec![Token::Number("1\u{2009}000,5".to_owned())]),
+            (comma, r"(1, 2)", vec![Token::Paren("("), Token::Number("1".to_owned()), Token::Operator(',')]),
+            (comma, r"3.14", vec![Token::Number("3".to_owned()), Token::Operator('.')]),
+            (exponent, r"1.5e-3", vec![Token::Number("1.5e-3".to_owned())]),
+            (exponent, r"6E23", vec![Token::Number("6E23".to_owned())]),
+            (exponent, r"2e", vec![Token::Number("2".to_owned()), Token::Letter('e', Variant::Italic)]),
+            (grouped, r"1\,000\,000", vec![Token::Number("1\u{2009}000\u{2009}000".to_owned())]),
+            (grouped, r"1{,}000", vec![Token::Number("1,000".to_owned())]),
+            (grouped, "1\u{202f}000", vec![Token::Number("1\u{202f}000".to_owned())]),
+            (grouped, r"1\,x", vec![Token::Number("1".to_owned()), Token::Space(Length::em(3./18.))]),
+        ];
+
+        for (format, problem, answer) in problems.iter() {
+            let mut lexer = Lexer::with_number_format(problem, *format);
+            for answer in answer.iter() {
+                assert_eq!(&lexer.next_token(), answer);
+            }
+        }
+    }
+
+    #[test]
+    fn text_lexer_test() {
+        let problems = [
+            (r"a  b", vec![TextToken::Char('a'), TextToken::Space, TextToken::Char('b'), TextToken::EOF]),
+            (r"\{x\} \%", vec![
+                TextToken::Char('{'), TextToken::Char('x'), TextToken::Char('}'), 
+                TextToken::Space, TextToken::Char('%'), TextToken::EOF,
+            ]),
+            (r"{\foo bar}", vec![
+                TextToken::LBrace, TextToken::Command("foo".to_owned()), TextToken::Char('b'),
+                TextToken::Char('a'), TextToken::Char('r'), TextToken::RBrace, TextToken::EOF,
+            ]),
+            (r"a--b---`c''", vec![
+                TextToken::Char('a'), TextToken::Char('–'), TextToken::Char('b'), TextToken::Char('—'),
+                TextToken::Char('‘'), TextToken::Char('c'), TextToken::Char('”'), TextToken::EOF,
+            ]),
+            (r"a $x \$$ \(y\)", vec![
+                TextToken::Char('a'), TextToken::Space, TextToken::Math(r"x \$".to_owned()),
+                TextToken::Space, TextToken::Math("y".to_owned()), TextToken::EOF,
+            ]),
+        ];
+
+        for (problem, answer) in problems.iter() {
+            let mut lexer = Lexer::new(problem);
+            for answer in answer.iter() {
+                assert_eq!(&lexer.next_text_token(), answer);
+            }
+        }
+    }
+}
@@ -11,16 +11,40 @@
 //! - Binary relations, e.g. `=`, `>`, `<`, `\ll`, `:=`, ...
 //! - Binary operations, e.g. `+`. `-`, `*`, `/`, `\times`, `\otimes`, ...
 //! - Basic LaTeX commands, e.g. `\sqrt`, `\frac`, `\sin`, `\binom`, ...
-//! - Parentheses, e.g., `\left\{ .. \middle| .. \right]`, ...
+//! - Fractions, e.g. `\dfrac`, `\tfrac`, `\cfrac`, `\genfrac`, `\sfrac`, `{a \over b}`, `{n \choose k}`, ...
+//! - Parentheses, e.g., `\left\{ .. \middle| .. \right]`, `\left\lvert .. \right\rVert`, `\bigl( .. \bigr)`, `\Bigm|`, ...
 //! - Integrals, e.g., `\int_0^\infty`, `\iint`, `\oint`, ...
 //! - Big operators, e.g., `\sum`, `\prod`, `\bigcup_{i = 0}^\infty`, ...
+//! - Accents, e.g. `\hat{x}`, `\widehat{xy}`, `\overline{AB}`, `\mathring{A}`, `\dddot{x}`, `\overleftrightarrow{AB}`, `\utilde{x}`, ...
 //! - Limits and overset/underset, e.g., `\lim`, `\overset{}{}`, `\overbrace{}{}`, ...
-//! - Font styles, e.g. `\mathrm`, `\mathbf`, `\bm`, `\mathit`, `\mathsf`, `\mathscr`, `\mathbb`, `\mathfrak`, `\texttt`.
+//! - Text, e.g. `\text{for all $x \in A$}`, `\textrm`, `\textbf`, `\textit`, `\textsf`, `\texttt`, `\mbox`, `\emph`.
+//!   - Accents, symbols and ligatures in text, e.g. `\text{Schr\"odinger}`, `\'e`, `\c{c}`, `\ss`, `--`, `---`, ``` `` '' ```, `\textdegree`.
+//! - Font styles, e.g. `\mathrm`, `\mathbf`, `\bm`, `\mathit`, `\mathsf`, `\mathscr`, `\mathbb`, `\mathfrak`.
+//!   - Digits, Greek letters and symbols are also styled, e.g. `\mathbf{0}`, `\mathbb{1}`, `\mathbf{\Omega}`. `\boldsymbol` and `\bm` keep the shape, e.g. `\boldsymbol{\alpha}` is bold italic and `\boldsymbol{\Omega}` is bold upright.
 //!   - MathML lacks calligraphic mathvariant: https://github.com/mathml-refresh/mathml/issues/61
-//! - White spaces, e.g., `\!`, `\,`, `\:`, `\;`, `\ `, `\quad`, `\qquad`.
+//!   - Declarative font switches `\rm`, `\bf`, `\it`, `\sf`, `\tt`, `\cal`, `\Bbb` and `\frak` apply until the end of the group.
+//! - Style declarations, e.g. `\displaystyle`, `\textstyle`, `\scriptstyle`, `\scriptscriptstyle`.
+//! - White spaces, e.g., `\!`, `\,`, `\:`, `\;`, `\ `, `\quad`, `\qquad`, `\enspace`, `\thinspace`, `\negthinspace`.
+//! - Explicit spaces with TeX units, e.g. `\hspace{2mm}`, `\mspace{3mu}`, `\kern-1pt`, `\mkern6mu`, `\hskip`, `\mskip`.
 //! - Matrix, e.g. `\begin{matrix}`, `\begin{pmatrix}`, `\begin{bmatrix}`, `\begin{vmatrix}`.
+//!   - Row gaps, e.g. `\\[4pt]`.
+//! - Dots, e.g. `\dots` (chosen by the next token), `\dotsb`, `\dotsc`, `\iddots`, `\hdotsfor{n}` directly in a matrix cell.
 //! - Multi-line equation `\begin{align}` (experimental).
+//! - Comments `%`, and non-breaking space `~`.
 //! - Feynman slash notation: `\slashed{\partial}`.
+//! - Boxes, e.g. `\boxed`, `\fbox`, `\framebox`, `\enclose{circle}{x}`, `\bbox[yellow,5px]{x}`.
+//! - Phantoms and overlaps, e.g. `\phantom`, `\hphantom`, `\vphantom`, `\smash[t]`, `\mathstrut`, `\mathllap`, `\mathrlap`, `\mathclap`.
+//! - Math classes, e.g. `\mathrel{\sim}`, `\mathbin{\triangle}`, `\mathop{\mathrm{Res}}\limits_{z=0}`.
+//! - Modular arithmetic, e.g. `a \bmod b`, `a \equiv b \pmod{n}`, `\pod{n}`, `\mod{n}`.
+//! - Negated relations, e.g. `\not=`, `\not\in`.
+//! - Direct Unicode input, e.g. `a ≤ b`, `x → ∞`, `∑_i α_i`.
+//! - The unicode-math symbol commands, e.g. `\nleq`, `\varnothing`, `\llbracket`, `\oiint`, `\mbfA`.
+//! - Number formats, e.g. digit grouping `1\,000\,000`, `1{,}000`, the decimal comma `2,5` and the exponent form `1.5e-3`. They are disabled by default and enabled by [`NumberFormat`](struct.NumberFormat.html).
+//! - Characters by code point, e.g. `\unicode{x1D49C}`, `\char"2A`, `\symbol{8477}`.
+//! - Hyperlinks and HTML attributes, e.g. `\href{url}{x}`, `\url{url}`, `\class{name}{x}`, `\cssId{id}{x}`, `\style{css}{x}`, `\htmlData{key=value}{x}`. They are disabled by default and enabled by `Trust`.
+//!   - See [`Trust`](./struct.Trust.html) to enable them for trusted input.
+//! - Colors, e.g. `\color{red}`, `\textcolor[HTML]{FF8800}{x}`, `\colorbox{yellow}{text}`, `\fcolorbox`, `red!50!blue`.
+//! - Cancellations, e.g. `\cancel`, `\bcancel`, `\xcancel`, `\cancelto{0}{x}`, `\sout`.
 //! 
 //! ## Unsupported LaTeX commands
 //! 
@@ -72,6 +96,9 @@ pub mod token;
 pub mod ast;
 pub(crate) mod lexer;
 pub(crate) mod parse;
+pub(crate) mod color;
+pub(crate) mod text;
+pub(crate) mod symbol;
 mod error;
 pub use error::LatexError;
 use std::{fmt, fs, path::Path, io::Write};
@@ -92,9 +119,89 @@ impl fmt::Display for DisplayStyle {
     }
 }
 
-fn convert_content(latex: &str) -> Result<String, error::LatexError> {
-    let l = lexer::Lexer::new(latex);
-    let mut p = parse::Parser::new(l);
+/// Trust settings for commands which emit HTML attributes.
+/// 
+/// Each field allows or denies the corresponding commands.
+/// Denied commands are rendered without the attribute.
+/// 
+/// - `href`: `\href{url}{math}` and `\url{url}`
+/// - `class`: `\class{name}{math}`, `\htmlClass{name}{math}` and `class` of `\enclose`
+/// - `id`: `\cssId{id}{math}`, `\htmlId{id}{math}` and `id` of `\enclose`
+/// - `style`: `\style{css}{math}`, `\htmlStyle{css}{math}`, `style` of `\enclose` and CSS options of `\bbox`
+/// - `data`: `\htmlData{key=value, ..}{math}` and `data-*` of `\enclose`
+/// 
+/// The default trusts none of them, as KaTeX does. Use [`Trust::all()`](#method.all) only for trusted input.
+/// URLs with the `javascript:`, `data:` or `vbscript:` scheme are always rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trust {
+    pub href: bool,
+    pub class: bool,
+    pub id: bool,
+    pub style: bool,
+    pub data: bool,
+}
+
+impl Trust {
+    /// Allow all commands.
+    pub fn all() -> Self {
+        Trust { href: true, class: true, id: true, style: true, data: true }
+    }
+
+    /// Deny all commands.
+    pub fn none() -> Self {
+        Trust { href: false, class: false, id: false, style: false, data: false }
+    }
+}
+
+impl Default for Trust {
+    fn default() -> Self {
+        Trust::none()
+    }
+}
+
+/// Decimal separator of numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalSeparator {
+    /// `.`
+    Period,
+    /// `,`
+    Comma,
+}
+
+/// Number format settings for the lexer.
+/// 
+/// - `decimal_separator`: [`DecimalSeparator::Period`](enum.DecimalSeparator.html) (e.g. `3.14`) or `Comma` (e.g. `3,14`)
+/// - `grouping`: whether `{,}`, `\,` and thin spaces between digits group them, e.g. `1\,000\,000`
+/// - `exponent`: whether the exponent form such as `1.5e-3` is a part of the number
+/// 
+/// `{,}` is the decimal separator if `decimal_separator` is `Comma`.
+/// The default is `.` without grouping and the exponent form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    pub decimal_separator: DecimalSeparator,
+    pub grouping: bool,
+    pub exponent: bool,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat { decimal_separator: DecimalSeparator::Period, grouping: false, exponent: false }
+    }
+}
+
+/// Conversion options.
+/// 
+/// - `trust`: see [`Trust`](struct.Trust.html)
+/// - `number_format`: see [`NumberFormat`](struct.NumberFormat.html)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Options {
+    pub trust: Trust,
+    pub number_format: NumberFormat,
+}
+
+fn convert_content(latex: &str, display: DisplayStyle, trust: Trust, number: NumberFormat) -> Result<String, error::LatexError> {
+    let l = lexer::Lexer::with_number_format(latex, number);
+    let mut p = parse::Parser::new(l, display, trust);
     let nodes = p.parse()?;
 
     let mathml = nodes.iter()
@@ -121,7 +228,40 @@ fn convert_content(latex: &str) -> Result<String, error::LatexError> {
 /// ```
 /// 
 pub fn latex_to_mathml(latex: &str, display: DisplayStyle) -> Result<String, error::LatexError> {
-    let mathml = convert_content(latex)?;
+    latex_to_mathml_with_trust(latex, display, Trust::default())
+}
+
+/// Convert LaTeX text to MathML with given trust settings.
+/// 
+/// ```rust
+/// use latex2mathml::{latex_to_mathml_with_trust, DisplayStyle, Trust};
+/// 
+/// let latex = r#"\href{https://example.com}{x} + \class{term}{y}"#;
+/// let trust = Trust { href: false, ..Trust::all() };
+/// let mathml = latex_to_mathml_with_trust(latex, DisplayStyle::Inline, trust).unwrap();
+/// assert!(!mathml.contains("href"));
+/// assert!(mathml.contains(r#"class="term""#));
+/// ```
+/// 
+pub fn latex_to_mathml_with_trust(latex: &str, display: DisplayStyle, trust: Trust) -> Result<String, error::LatexError> {
+    latex_to_mathml_with_options(latex, display, Options { trust, ..Options::default() })
+}
+
+/// Convert LaTeX text to MathML with given options.
+/// 
+/// ```rust
+/// use latex2mathml::{latex_to_mathml_with_options, DisplayStyle, DecimalSeparator, NumberFormat, Options};
+/// 
+/// let options = Options {
+///     number_format: NumberFormat { decimal_separator: DecimalSeparator::Comma, ..NumberFormat::default() },
+///     ..Options::default()
+/// };
+/// let mathml = latex_to_mathml_with_options(r#"x = 2,5"#, DisplayStyle::Inline, options).unwrap();
+/// assert!(mathml.contains("<mn>2,5</mn>"));
+/// ```
+/// 
+pub fn latex_to_mathml_with_options(latex: &str, display: DisplayStyle, options: Options) -> Result<String, error::LatexError> {
+    let mathml = convert_content(latex, display, options.trust, options.number_format)?;
 
     Ok(format!(
         r#"<math xmlns="http://www.w3.org/1998/Math/MathML" display="{}">{}</math>"#,
@@ -150,6 +290,29 @@ pub fn latex_to_mathml(latex: &str, display: DisplayStyle) -> Result<String, err
 /// `examples/document.rs` gives a sample code using this function.
 /// 
 pub fn replace(input: &str) -> Result<String, error::LatexError> {
+    replace_with_trust(input, Trust::default())
+}
+
+/// Find LaTeX equations and replace them to MathML with given trust settings.
+/// 
+/// ```rust
+/// use latex2mathml::{replace_with_trust, Trust};
+/// 
+/// let input = r#"See $\href{https://example.com}{x}$."#;
+/// let output = replace_with_trust(input, Trust { href: true, ..Trust::none() }).unwrap();
+/// assert!(output.contains(r#"href="https://example.com""#));
+/// ```
+/// 
+pub fn replace_with_trust(input: &str, trust: Trust) -> Result<String, error::LatexError> {
+    replace_with_options(input, Options { trust, ..Options::default() })
+}
+
+/// Find LaTeX equations and replace them to MathML with given options.
+/// 
+/// See [`replace`](fn.replace.html) and [`Options`](struct.Options.html).
+/// 
+#[allow(clippy::op_ref, clippy::byte_char_slices)]
+pub fn replace_with_options(input: &str, options: Options) -> Result<String, error::LatexError> {
     let mut input: Vec<u8> = input.as_bytes().to_owned();
 
     //**** Convert block-math ****//
@@ -170,7 +333,7 @@ pub fn replace(input: &str) -> Result<String, error::LatexError> {
             { // convert LaTeX to MathML
                 let input = &input[idx[i]+2..idx[i+1]];
                 let input = unsafe { std::str::from_utf8_unchecked(input) };
-                let mathml = latex_to_mathml(input, DisplayStyle::Block)?;
+                let mathml = latex_to_mathml_with_options(input, DisplayStyle::Block, options)?;
                 output.extend_from_slice(mathml.as_bytes());
             }
 
@@ -202,7 +365,7 @@ pub fn replace(input: &str) -> Result<String, error::LatexError> {
             { // convert LaTeX to MathML
                 let input = &input[idx[i]+1..idx[i+1]];
                 let input = unsafe { std::str::from_utf8_unchecked(input) };
-                let mathml = latex_to_mathml(input, DisplayStyle::Inline)?;
+                let mathml = latex_to_mathml_with_options(input, DisplayStyle::Inline, options)?;
                 output.extend_from_slice(mathml.as_bytes());
             }
 
@@ -248,14 +411,31 @@ pub fn replace(input: &str) -> Result<String, error::LatexError> {
 /// will be converted into MathML. 
 /// 
 pub fn convert_html<P: AsRef<Path>>(path: P) -> Result<(), Box<dyn std::error::Error>> {
+    convert_html_with_trust(path, Trust::default())
+}
+
+/// Convert all LaTeX expressions for all HTMLs in a given directory with given trust settings.
+/// 
+/// See [`convert_html`](fn.convert_html.html) and [`Trust`](struct.Trust.html).
+/// 
+pub fn convert_html_with_trust<P: AsRef<Path>>(path: P, trust: Trust) -> Result<(), Box<dyn std::error::Error>> {
+    convert_html_with_options(path, Options { trust, ..Options::default() })
+}
+
+/// Convert all LaTeX expressions for all HTMLs in a given directory with given options.
+/// 
+/// See [`convert_html`](fn.convert_html.html) and [`Options`](struct.Options.html).
+/// 
+#[allow(clippy::needless_borrows_for_generic_args)]
+pub fn convert_html_with_options<P: AsRef<Path>>(path: P, options: Options) -> Result<(), Box<dyn std::error::Error>> {
     if path.as_ref().is_dir() {
         for entry in fs::read_dir(path)?.filter_map(Result::ok) {
-            convert_html(&entry.path())?
+            convert_html_with_options(&entry.path(), options)?
         }
     } else if path.as_ref().is_file() {
         if let Some(ext) = path.as_ref().extension() {
             if ext == "html" {
-                match convert_latex(&path) {
+                match convert_latex(&path, options) {
                     Ok(_) => (),
                     Err(e) => eprintln!("LaTeX2MathML Error: {}", e),
                 }
@@ -266,9 +446,10 @@ pub fn convert_html<P: AsRef<Path>>(path: P) -> Result<(), Box<dyn std::error::E
     Ok(())
 }
 
-fn convert_latex<P: AsRef<Path>>(fp: P) -> Result<(), Box<dyn std::error::Error>> {
+#[allow(clippy::op_ref)]
+fn convert_latex<P: AsRef<Path>>(fp: P, options: Options) -> Result<(), Box<dyn std::error::Error>> {
     let original = fs::read_to_string(&fp)?;
-    let converted = replace(&original)?;
+    let converted = replace_with_options(&original, options)?;
     if &original != &converted {
         let mut fp = fs::File::create(fp)?;
         fp.write_all(converted.as_bytes())?;
@@ -279,7 +460,7 @@ fn convert_latex<P: AsRef<Path>>(fp: P) -> Result<(), Box<dyn std::error::Error>
 
 #[cfg(test)]
 mod tests {
-    use super::convert_content;
+    use super::{convert_content, DisplayStyle, Trust, NumberFormat};
 
     #[test]
     fn it_works() {
@@ -298,9 +479,182 @@ mod tests {
             (r"\frac{1}{2}",  "<mfrac><mn>1</mn><mn>2</mn></mfrac>"),
             (r"\frac12",      "<mfrac><mn>1</mn><mn>2</mn></mfrac>"),
             (r"\frac{12}{5}", "<mfrac><mn>12</mn><mn>5</mn></mfrac>"),
+            (r"\dfrac{1}{2}", r#"<mstyle displaystyle="true"><mrow><mfrac><mn>1</mn><mn>2</mn></mfrac></mrow></mstyle>"#),
+            (r"\tfrac12",     r#"<mstyle displaystyle="false"><mrow><mfrac><mn>1</mn><mn>2</mn></mfrac></mrow></mstyle>"#),
+            (r"\cfrac[l]{1}{x}", r#"<mstyle displaystyle="true"><mrow><mfrac numalign="left"><mn>1</mn><mi>x</mi></mfrac></mrow></mstyle>"#),
+            (r"\sfrac{1}{2}", r#"<mfrac bevelled="true"><mn>1</mn><mn>2</mn></mfrac>"#),
+            (
+                r"\genfrac{(}{)}{0pt}{1}{a}{b}",
+                r#"<mstyle displaystyle="false"><mrow><mrow><mo stretchy="true" form="prefix">(</mo><mfrac linethickness="0pt"><mi>a</mi><mi>b</mi></mfrac><mo stretchy="true" form="postfix">)</mo></mrow></mrow></mstyle>"#
+            ),
+            (r"\genfrac{}{}{}{}{a}{b}", "<mfrac><mi>a</mi><mi>b</mi></mfrac>"),
+            (r"{a+1 \over b}", "<mfrac><mrow><mi>a</mi><mo>+</mo><mn>1</mn></mrow><mi>b</mi></mfrac>"),
+            (r"a \atop b",     r#"<mfrac linethickness="0"><mi>a</mi><mi>b</mi></mfrac>"#),
+            (r"{n \choose k}", r#"<mrow><mo stretchy="true" form="prefix">(</mo><mfrac linethickness="0"><mi>n</mi><mi>k</mi></mfrac><mo stretchy="true" form="postfix">)</mo></mrow>"#),
+            (r"{n \brack k}",  r#"<mrow><mo stretchy="true" form="prefix">[</mo><mfrac linethickness="0"><mi>n</mi><mi>k</mi></mfrac><mo stretchy="true" form="postfix">]</mo></mrow>"#),
+            (r"\displaystyle x", r#"<mstyle displaystyle="true"><mi>x</mi></mstyle>"#),
+            (r"{\scriptstyle a + b} c", r#"<mstyle displaystyle="false" scriptlevel="1"><mrow><mi>a</mi><mo>+</mo><mi>b</mi></mrow></mstyle><mi>c</mi>"#),
+            (r"{\scriptstyle a \over b}", r#"<mfrac><mstyle displaystyle="false" scriptlevel="1"><mi>a</mi></mstyle><mi>b</mi></mfrac>"#),
+            (r"x^{\displaystyle a}", r#"<msup><mi>x</mi><mstyle displaystyle="true" scriptlevel="0"><mi>a</mi></mstyle></msup>"#),
+            (r"x^{\dfrac{a}{b}}", r#"<msup><mi>x</mi><mstyle displaystyle="true" scriptlevel="0"><mrow><mfrac><mi>a</mi><mi>b</mi></mfrac></mrow></mstyle></msup>"#),
+            (
+                r"\begin{matrix} \textstyle a & b \end{matrix}",
+                r#"<mtable><mtr><mtd><mstyle displaystyle="false"><mi>a</mi></mstyle></mtd><mtd><mi>b</mi></mtd></mtr></mtable>"#
+            ),
+            (r"\boxed{E = mc^2}", r#"<menclose notation="box"><mrow><mi>E</mi><mo>=</mo><mi>m</mi><msup><mi>c</mi><mn>2</mn></msup></mrow></menclose>"#),
+            (r"\fbox{a & b}", r#"<menclose notation="box"><mtext>a &amp; b</mtext></menclose>"#),
+            (r"\framebox[2cm][l]{text}", r#"<menclose notation="box"><mtext>text</mtext></menclose>"#),
+            (
+                r#"\enclose{circle}[mathcolor="red"]{x}"#,
+                r#"<menclose notation="circle" mathcolor="red"><mi>x</mi></menclose>"#
+            ),
+            (r"\enclose{updiagonalstrike madruwb}{x}", r#"<menclose notation="updiagonalstrike madruwb"><mi>x</mi></menclose>"#),
+            (
+                r"\bbox[yellow,5px]{x}",
+                r##"<mpadded mathbackground="#ffff00" width="+10px" height="+5px" depth="+5px" lspace="5px"><mi>x</mi></mpadded>"##
+            ),
+            (
+                r#"\enclose{circle}[mathcolor="red", style="border: 1px", onclick="f()", xmlns=evil, data-x=1]{x}"#,
+                r#"<menclose notation="circle" mathcolor="red" style="border: 1px" data-x="1"><mi>x</mi></menclose>"#
+            ),
+            (r"\bbox[border:1px solid red]{x}", r#"<mpadded style="border:1px solid red"><mi>x</mi></mpadded>"#),
+            (r"\boxed{x}^2", r#"<msup><menclose notation="box"><mi>x</mi></menclose><mn>2</mn></msup>"#),
+            (r"\bbox[red]{x}_1", r##"<msub><mpadded mathbackground="#ff0000"><mi>x</mi></mpadded><mn>1</mn></msub>"##),
+            (r"\enclose{circle}{x}^2", r#"<msup><menclose notation="circle"><mi>x</mi></menclose><mn>2</mn></msup>"#),
+            (r"\cancel{x}",  r#"<menclose notation="updiagonalstrike"><mi>x</mi></menclose>"#),
+            (r"\bcancel{x}", r#"<menclose notation="downdiagonalstrike"><mi>x</mi></menclose>"#),
+            (r"\xcancel{x}", r#"<menclose notation="updiagonalstrike downdiagonalstrike"><mi>x</mi></menclose>"#),
+            (r"\sout{x}",    r#"<menclose notation="horizontalstrike"><mi>x</mi></menclose>"#),
+            (r"\cancelto{0}{x}", r#"<msup><menclose notation="updiagonalarrow"><mi>x</mi></menclose><mn>0</mn></msup>"#),
+            (r"\cancel{x}^2", r#"<msup><menclose notation="updiagonalstrike"><mi>x</mi></menclose><mn>2</mn></msup>"#),
+            (r"\sout{x}_1", r#"<msub><menclose notation="horizontalstrike"><mi>x</mi></menclose><mn>1</mn></msub>"#),
+            (r"\cancelto{0}{x}^2", r#"<msup><msup><menclose notation="updiagonalarrow"><mi>x</mi></menclose><mn>0</mn></msup><mn>2</mn></msup>"#),
+            (r"{\color{red} x + y} z", r##"<mstyle mathcolor="#ff0000"><mrow><mi>x</mi><mo>+</mo><mi>y</mi></mrow></mstyle><mi>z</mi>"##),
+            (r"\textcolor[HTML]{FF8800}{x}", r##"<mstyle mathcolor="#ff8800"><mi>x</mi></mstyle>"##),
+            (r"\textcolor{red!50!blue}{x}", r##"<mstyle mathcolor="#800080"><mi>x</mi></mstyle>"##),
+            (r"\textcolor{red}{x}^2", r##"<msup><mstyle mathcolor="#ff0000"><mi>x</mi></mstyle><mn>2</mn></msup>"##),
+            (
+                r"\colorbox{yellow}{text}",
+                r##"<mpadded mathbackground="#ffff00" width="+6pt" height="+3pt" depth="+3pt" lspace="3pt"><mtext>text</mtext></mpadded>"##
+            ),
+            (
+                r"\fcolorbox{red}[rgb]{1,1,0}{text}",
+                r##"<mpadded mathbackground="#ffff00" style="border: 0.4pt solid #ff0000" width="+6pt" height="+3pt" depth="+3pt" lspace="3pt"><mtext>text</mtext></mpadded>"##
+            ),
+            (r"\phantom{=}",  "<mphantom><mo>=</mo></mphantom>"),
+            (r"\hphantom{x}", r#"<mpadded height="0" depth="0"><mphantom><mi>x</mi></mphantom></mpadded>"#),
+            (r"\vphantom{\frac{a}{b}}", r#"<mpadded width="0"><mphantom><mfrac><mi>a</mi><mi>b</mi></mfrac></mphantom></mpadded>"#),
+            (r"\smash{y}",    r#"<mpadded height="0" depth="0"><mi>y</mi></mpadded>"#),
+            (r"\smash[b]{y}", r#"<mpadded depth="0"><mi>y</mi></mpadded>"#),
+            (r"\mathstrut",   r#"<mpadded width="0"><mphantom><mo>(</mo></mphantom></mpadded>"#),
+            (r"\strut",       r#"<mpadded width="0" height="8.6pt" depth="3pt"><mrow></mrow></mpadded>"#),
+            (r"\mathllap{x}", r#"<mpadded width="0" lspace="-1width"><mi>x</mi></mpadded>"#),
+            (r"\phantom{x}^2", "<msup><mphantom><mi>x</mi></mphantom><mn>2</mn></msup>"),
+            (r"\smash{y}_1", r#"<msub><mpadded height="0" depth="0"><mi>y</mi></mpadded><mn>1</mn></msub>"#),
+            (r"\mathllap{x}^2", r#"<msup><mpadded width="0" lspace="-1width"><mi>x</mi></mpadded><mn>2</mn></msup>"#),
+            (r"\mathrlap{x}", r#"<mpadded width="0"><mi>x</mi></mpadded>"#),
+            (r"\mathclap{x}", r#"<mpadded width="0" lspace="-0.5width"><mi>x</mi></mpadded>"#),
+            (r"\href{javascript:alert(1)}{x}", "<mi>x</mi>"),
+            ("\\href{ Java\tScript:alert(1)}{x}", "<mi>x</mi>"),
+            (r"\url{data:text/html,x}", "<mtext>data:text/html,x</mtext>"),
+            (r"\href{VBScript:x}{x}", "<mi>x</mi>"),
+            (r"\href{/wiki/a:b}{x}", r#"<mrow href="/wiki/a:b"><mi>x</mi></mrow>"#),
+            (r"\href{https://example.com/?a=1&b=2}{x}", r#"<mrow href="https://example.com/?a=1&amp;b=2"><mi>x</mi></mrow>"#),
+            (r"\href{https://example.com/a%20b}{x}", r#"<mrow href="https://example.com/a%20b"><mi>x</mi></mrow>"#),
+            (r"\url{https://example.com/a%20b}", r#"<mrow href="https://example.com/a%20b"><mtext>https://example.com/a%20b</mtext></mrow>"#),
+            (r"\url{https://example.com}", r#"<mrow href="https://example.com"><mtext>https://example.com</mtext></mrow>"#),
+            (r"\class{term}{x + y}", r#"<mrow class="term"><mrow><mi>x</mi><mo>+</mo><mi>y</mi></mrow></mrow>"#),
+            (r"\href{u}{x}^2", r#"<msup><mrow href="u"><mi>x</mi></mrow><mn>2</mn></msup>"#),
+            (r"\htmlData{k=v}{x}_1", r#"<msub><mrow data-k="v"><mi>x</mi></mrow><mn>1</mn></msub>"#),
+            (r"\cssId{eq1}{x}", r#"<mrow id="eq1"><mi>x</mi></mrow>"#),
+            (r#"\style{color: "red"}{x}"#, r#"<mrow style="color: &quot;red&quot;"><mi>x</mi></mrow>"#),
+            (r"\htmlData{foo=a, bar-baz=b}{x}", r#"<mrow data-foo="a" data-bar-baz="b"><mi>x</mi></mrow>"#),
+            (r"\unicode{x1D49C} \unicode{x2A01} \unicode{x263A}", "<mi>𝒜</mi><mo>⨁</mo><mtext>☺</mtext>"),
+            (r#"a \char"2A b"#, "<mi>a</mi><mo>*</mo><mi>b</mi>"),
+            (r"\symbol{8477}^2", "<msup><mi>ℝ</mi><mn>2</mn></msup>"),
+            (r"a \not= b",      "<mi>a</mi><mo>≠</mo><mi>b</mi>"),
+            (r"x \not\in A",   "<mi>x</mi><mo>∉</mo><mi>A</mi>"),
+            (r"\not\subseteq", "<mo>⊈</mo>"),
+            (r"\not\mid",      "<mo>∤</mo>"),
+            (r"\not\perp",     "<mo>⊥&#x0338;</mo>"),
+            (r"\not\ni",       "<mo>∌</mo>"),
+            (r"\not a^2",       r#"<msup><mi mathvariant="italic">a&#x0338;</mi><mn>2</mn></msup>"#),
+            (r"\not\mathrel{R}", r#"<menclose notation="updiagonalstrike"><mrow><mspace width="0.2778em"/><mi>R</mi><mspace width="0.2778em"/></mrow></menclose>"#),
+            (r"\not|",         "<mo>|&#x0338;</mo>"),
+            (r"a \mathrel{\sim} b", r#"<mi>a</mi><mo form="infix" lspace="0.2778em" rspace="0.2778em">∼</mo><mi>b</mi>"#),
+            (r"a \mathbin{x} b", r#"<mi>a</mi><mrow><mspace width="0.2222em"/><mi>x</mi><mspace width="0.2222em"/></mrow><mi>b</mi>"#),
+            (r"\mathord{+}1", r#"<mo lspace="0em" rspace="0em">+</mo><mn>1</mn>"#),
+            (r"\mathop{\mathrm{Res}}_{z=0} f", r#"<munder><mo form="prefix" lspace="0.1667em" rspace="0.1667em" movablelimits="true">Res</mo><mrow><mi>z</mi><mo>=</mo><mn>0</mn></mrow></munder><mi>f</mi>"#),
+            (r"\mathop{\mathrm{Res}}\limits_{z=0}^{n}", r#"<munderover><mo form="prefix" lspace="0.1667em" rspace="0.1667em" movablelimits="false">Res</mo><mrow><mi>z</mi><mo>=</mo><mn>0</mn></mrow><mi>n</mi></munderover>"#),
+            (r"\mathop{T}\nolimits_{n}", r#"<msub><mrow><mspace width="0.1667em"/><mi>T</mi><mspace width="0.1667em"/></mrow><mi>n</mi></msub>"#),
+            (r"\sum\limits_{i=1}^n", r#"<munderover><mo>∑</mo><mrow><mi>i</mi><mo>=</mo><mn>1</mn></mrow><mi>n</mi></munderover>"#),
+            (r"\sum\limits^n", r#"<mover><mo>∑</mo><mi>n</mi></mover>"#),
+            (r"\sum\nolimits_i", r#"<msub><mo>∑</mo><mi>i</mi></msub>"#),
+            (r"\int\limits_0^1", r#"<munderover><mo>∫</mo><mn>0</mn><mn>1</mn></munderover>"#),
+            (r"\int\nolimits_0^1", r#"<msubsup><mo>∫</mo><mn>0</mn><mn>1</mn></msubsup>"#),
+            (r"\lim\nolimits_{n}", r#"<msub><mi>lim</mi><mi>n</mi></msub>"#),
+            (r"x\limits_i", r#"<msub><mi>x</mi><mi>i</mi></msub>"#),
+            (r"\nolimits x", r#"<mi>x</mi>"#),
+            (r"\left\| x \right\Vert", r#"<mrow><mo stretchy="true" form="prefix">‖</mo><mi>x</mi><mo stretchy="true" form="postfix">‖</mo></mrow>"#),
+            (r"\|x\|", "<mo>‖</mo><mi>x</mi><mo>‖</mo>"),
+            (r"\left\lvert x \middle/ y \right\rVert", r#"<mrow><mo stretchy="true" form="prefix">|</mo><mrow><mi>x</mi><mo stretchy="true">/</mo><mi>y</mi></mrow><mo stretchy="true" form="postfix">‖</mo></mrow>"#),
+            (r"\left< x \right>", r#"<mrow><mo stretchy="true" form="prefix">⟨</mo><mi>x</mi><mo stretchy="true" form="postfix">⟩</mo></mrow>"#),
+            (r"\left\ulcorner x \right\backslash", r#"<mrow><mo stretchy="true" form="prefix">⌜</mo><mi>x</mi><mo stretchy="true" form="postfix">\</mo></mrow>"#),
+            (r"\left. x \right\uparrow", r#"<mrow><mo stretchy="true" form="prefix"></mo><mi>x</mi><mo stretchy="true" form="postfix">↑</mo></mrow>"#),
+            (r"\bigl( x \bigr)", r#"<mo form="prefix" stretchy="true" symmetric="true" maxsize="1.2em" minsize="1.2em">(</mo><mi>x</mi><mo form="postfix" stretchy="true" symmetric="true" maxsize="1.2em" minsize="1.2em">)</mo>"#),
+            (r"a \Bigm| b", r#"<mi>a</mi><mo form="infix" stretchy="true" symmetric="true" maxsize="1.623em" minsize="1.623em">|</mo><mi>b</mi>"#),
+            (r"\Bigg\lbrace", r#"<mo stretchy="true" symmetric="true" maxsize="2.470em" minsize="2.470em">{</mo>"#),
+            (r"\pmod{n}^2", r#"<msup><mrow><mspace width="1em"/><mo>(</mo><mo lspace="0em" rspace="0em">mod</mo><mspace width="0.3333em"/><mi>n</mi><mo>)</mo></mrow><mn>2</mn></msup>"#),
+            (r"a \bmod b", r#"<mi>a</mi><mrow><mspace width="0.2778em"/><mo lspace="0em" rspace="0em">mod</mo><mspace width="0.2778em"/></mrow><mi>b</mi>"#),
+            (r"a \equiv b \pmod{n}", r#"<mi>a</mi><mo>≡</mo><mi>b</mi><mrow><mspace width="1em"/><mo>(</mo><mo lspace="0em" rspace="0em">mod</mo><mspace width="0.3333em"/><mi>n</mi><mo>)</mo></mrow>"#),
+            (r"a \equiv b \pod{n}", r#"<mi>a</mi><mo>≡</mo><mi>b</mi><mrow><mspace width="1em"/><mo>(</mo><mi>n</mi><mo>)</mo></mrow>"#),
+            (r"a \equiv b \mod n", r#"<mi>a</mi><mo>≡</mo><mi>b</mi><mrow><mspace width="1em"/><mo lspace="0em" rspace="0em">mod</mo><mspace width="0.3333em"/><mi>n</mi></mrow>"#),
+            (r"\text{for all x > 0}", "<mtext>for all x &gt; 0</mtext>"),
+            (r"\text{step 2}", "<mtext>step 2</mtext>"),
+            (r"x \text{ if } y", "<mi>x</mi><mtext>\u{a0}if\u{a0}</mtext><mi>y</mi>"),
+            (r"\text{a {b}  \{c\} 50\%}", "<mtext>a b {c} 50%</mtext>"),
+            (r"\text{for all $x \in A$}", "<mrow><mtext>for all\u{a0}</mtext><mi>x</mi><mo>∈</mo><mi>A</mi></mrow>"),
+            (r"\text{if \(n \geq 0\), so}", "<mrow><mtext>if\u{a0}</mtext><mi>n</mi><mo>≥</mo><mn>0</mn><mtext>, so</mtext></mrow>"),
+            (r"\text{$\frac{a}{b}$}", "<mfrac><mi>a</mi><mi>b</mi></mfrac>"),
+            (r"\textbf{step 2}", r#"<mtext mathvariant="bold">step 2</mtext>"#),
+            (r"\textbf{a \textit{b} c}", "<mrow><mtext mathvariant=\"bold\">a\u{a0}</mtext><mtext mathvariant=\"bold-italic\">b</mtext><mtext mathvariant=\"bold\">\u{a0}c</mtext></mrow>"),
+            (r"\textsf{\textbf{x}}", r#"<mtext mathvariant="bold-sans-serif">x</mtext>"#),
+            (r"\textit{an \emph{important} word}", "<mrow><mtext mathvariant=\"italic\">an\u{a0}</mtext><mtext>important</mtext><mtext mathvariant=\"italic\">\u{a0}word</mtext></mrow>"),
+            (r"\mbox{a \textrm{b}}", "<mtext>a b</mtext>"),
+            (r"\texttt{$\text{x}$}", r#"<mtext mathvariant="monospace">x</mtext>"#),
+            (r#"\text{Schr\"odinger}"#, "<mtext>Schrödinger</mtext>"),
+            (r"\text{caf\'e, gar\c{c}on, \'{\i}, \v s}", "<mtext>café, garçon, í, š</mtext>"),
+            (r"\text{Stra\ss e, \O{}resund}", "<mtext>Straße, Øresund</mtext>"),
+            (r"\text{1--2, yes---no, ``quoted''}", "<mtext>1–2, yes—no, “quoted”</mtext>"),
+            (r"\text{20\textdegree C}", "<mtext>20°C</mtext>"),
+            (r"a ≤ b", "<mi>a</mi><mo>≤</mo><mi>b</mi>"),
+            (r"x → ∞", r#"<mi>x</mi><mo>→</mo><mi mathvariant="normal">∞</mi>"#),
+            (r"∑_i α_i", "<munder><mo>∑</mo><mi>i</mi></munder><msub><mi>α</mi><mi>i</mi></msub>"),
+            (r"Γ", r#"<mi mathvariant="normal">Γ</mi>"#),
+            (r"⌈x⌉", "<mo>⌈</mo><mi>x</mi><mo>⌉</mo>"),
+            (r"\left⟨ x \right⟩", r#"<mrow><mo stretchy="true" form="prefix">⟨</mo><mi>x</mi><mo stretchy="true" form="postfix">⟩</mo></mrow>"#),
+            (r"\langle x \rangle", "<mo>⟨</mo><mi>x</mi><mo>⟩</mo>"),
+            (r"a \nleq b", "<mi>a</mi><mo>≰</mo><mi>b</mi>"),
+            (r"\varnothing", r#"<mi mathvariant="normal">∅</mi>"#),
+            (r"\circledS", r#"<mi mathvariant="normal">Ⓢ</mi>"#),
+            (r"\llbracket x \rrbracket", "<mo>⟦</mo><mi>x</mi><mo>⟧</mo>"),
+            (r"\oiint_S f", "<msub><mo>∯</mo><mi>S</mi></msub><mi>f</mi>"),
+            (r"\bigtimes_i A_i", "<munder><mo>⨉</mo><mi>i</mi></munder><msub><mi>A</mi><mi>i</mi></msub>"),
+            (r"\mbfA \mupalpha \Bbbone", r#"<mi>𝐀</mi><mi mathvariant="normal">α</mi><mn>𝟙</mn>"#),
+            (r"f(1\,2)", "<mi>f</mi><mo>(</mo><mn>1</mn><mspace width=\"0.1667em\"/><mn>2</mn><mo>)</mo>"),
+            (r#"\char"26 \char"3C"#, "<mo>&amp;</mo><mo>&lt;</mo>"),
+            (r"a < b > c", "<mi>a</mi><mo>&lt;</mo><mi>b</mi><mo>&gt;</mo><mi>c</mi>"),
+            (r"\not<", "<mo>≮</mo>"),
+            (r"\mathrel{<}", r#"<mo form="infix" lspace="0.2778em" rspace="0.2778em">&lt;</mo>"#),
             (r"x^2",          "<msup><mi>x</mi><mn>2</mn></msup>"),
             (r"g_{\mu\nu}",   "<msub><mi>g</mi><mrow><mi>μ</mi><mi>ν</mi></mrow></msub>"),
-            (r"\dot{x}",      "<mover><mi>x</mi><mo accent=\"true\">\u{02d9}</mo></mover>"),
+            (r"\dot{x}",      "<mover accent=\"true\"><mi>x</mi><mo stretchy=\"false\">\u{02d9}</mo></mover>"),
+            (r"\hat{x}^2",    r#"<msup><mover accent="true"><mi>x</mi><mo stretchy="false">^</mo></mover><mn>2</mn></msup>"#),
+            (r"\widehat{xy}", r#"<mover accent="true"><mrow><mi>x</mi><mi>y</mi></mrow><mo stretchy="true">^</mo></mover>"#),
+            (r"\overline{\tilde{a}}", r#"<mover accent="true"><mover accent="true"><mi>a</mi><mo stretchy="false">~</mo></mover><mo stretchy="true">‾</mo></mover>"#),
+            (r"\mathring{A}", r#"<mover accent="true"><mi>A</mi><mo stretchy="false">˚</mo></mover>"#),
+            (r"\sum^n", r#"<mover><mo>∑</mo><mi>n</mi></mover>"#),
+            (r"\underleftrightarrow{AB}", r#"<munder accentunder="true"><mrow><mi>A</mi><mi>B</mi></mrow><mo stretchy="true">↔</mo></munder>"#),
             (r"\sin x",       r#"<mi>sin</mi><mi>x</mi>"#),
             (r"\operatorname{sn} x", r#"<mi>sn</mi><mi>x</mi>"#),
             (r"\binom12",     r#"<mrow><mo stretchy="true" form="prefix">(</mo><mfrac linethickness="0"><mn>1</mn><mn>2</mn></mfrac><mo stretchy="true" form="postfix">)</mo></mrow>"#),
@@ -313,14 +667,79 @@ mod tests {
             (r"\int^1_0 dx",  r#"<msubsup><mo>∫</mo><mn>0</mn><mn>1</mn></msubsup><mi>d</mi><mi>x</mi>"#),
             (r"\bm{x}",       r#"<mi mathvariant="bold-italic">x</mi>"#),
             (r"\mathbb{R}",   r#"<mi mathvariant="double-struck">R</mi>"#),
+            (r"\mathbf{x^2}", r#"<msup><mi mathvariant="bold">x</mi><mn mathvariant="bold">2</mn></msup>"#),
+            (r"\mathbb{\frac{a}{b}}", r#"<mfrac><mi mathvariant="double-struck">a</mi><mi mathvariant="double-struck">b</mi></mfrac>"#),
+            (r"\mathbf{x}^2", r#"<msup><mi mathvariant="bold">x</mi><mn>2</mn></msup>"#),
+            (r"\mathbf x_i", r#"<msub><mi mathvariant="bold">x</mi><mi>i</mi></msub>"#),
+            (r"\mathbf{x \mathit{y}}", r#"<mrow><mi mathvariant="bold">x</mi><mi>y</mi></mrow>"#),
+            (r"\mathbf{0} \mathbb{1}", r#"<mn mathvariant="bold">0</mn><mn mathvariant="double-struck">1</mn>"#),
+            (r"\mathbf{\Omega \alpha}", r#"<mrow><mi mathvariant="bold">Ω</mi><mi mathvariant="bold">α</mi></mrow>"#),
+            (r"\boldsymbol{\Omega \alpha}", r#"<mrow><mi mathvariant="bold">Ω</mi><mi mathvariant="bold-italic">α</mi></mrow>"#),
+            (r"\bm{\nabla} \boldsymbol{\partial}", r#"<mo mathvariant="bold">∇</mo><mo mathvariant="bold-italic">∂</mo>"#),
+            (r"\boldsymbol{x + 1}", r#"<mrow><mi mathvariant="bold-italic">x</mi><mo mathvariant="bold">+</mo><mn mathvariant="bold">1</mn></mrow>"#),
+            (r"\boldsymbol{\alpha}^2", r#"<msup><mi mathvariant="bold-italic">α</mi><mn>2</mn></msup>"#),
+            (r"\boldsymbol{\mathrm{d}}", r#"<mi mathvariant="bold">d</mi>"#),
+            (r"\mathsf{\sin \Gamma}", r#"<mrow><mi mathvariant="sans-serif">sin</mi><mi mathvariant="sans-serif">Γ</mi></mrow>"#),
+            (r"{\bf A + 1} B", r#"<mrow><mi mathvariant="bold">A</mi><mo mathvariant="bold">+</mo><mn mathvariant="bold">1</mn></mrow><mi>B</mi>"#),
+            (r"{\rm d} \partial", r#"<mi mathvariant="normal">d</mi><mo mathvariant="italic">∂</mo>"#),
+            (r"{\rm \partial}", r#"<mo>∂</mo>"#),
             (r"\sum_{i = 0}^∞ i", r#"<munderover><mo>∑</mo><mrow><mi>i</mi><mo>=</mo><mn>0</mn></mrow><mi mathvariant="normal">∞</mi></munderover><mi>i</mi>"#),
             (r"\prod_n n",        r#"<munder><mo>∏</mo><mi>n</mi></munder><mi>n</mi>"#),
             (r"x\ y",         r#"<mi>x</mi><mspace width="1em"/><mi>y</mi>"#),
+            (r"x\,y",         r#"<mi>x</mi><mspace width="0.1667em"/><mi>y</mi>"#),
+            (r"x\hspace{2mm}y", r#"<mi>x</mi><mspace width="2mm"/><mi>y</mi>"#),
+            (r"x\mspace{3mu}y", r#"<mi>x</mi><mspace width="0.1667em"/><mi>y</mi>"#),
+            (r"x\kern-1pt y", r#"<mi>x</mi><mspace width="-1pt"/><mi>y</mi>"#),
+            (r"x\mkern6mu y", r#"<mi>x</mi><mspace width="0.3333em"/><mi>y</mi>"#),
+            (r"x\hskip 1.5em y", r#"<mi>x</mi><mspace width="1.5em"/><mi>y</mi>"#),
+            (r"x\hskip 2pt plus 1pt minus 1fil y", r#"<mi>x</mi><mspace width="2pt"/><mi>y</mi>"#),
+            (r"x\hspace{2mm plus 1mm} y", r#"<mi>x</mi><mspace width="2mm"/><mi>y</mi>"#),
+            (r"\kern2truein x", r#"<mspace width="2in"/><mi>x</mi>"#),
+            (r"x\hskip 2pt plus y", r#"<mi>x</mi><mspace width="2pt"/><mi>p</mi><mi>l</mi><mi>u</mi><mi>s</mi><mi>y</mi>"#),
+            (r"\hskip xy", r#"<mtext>[PARSE ERROR: Undefined("Command(\"hskip\")")]</mtext><mi>x</mi><mi>y</mi>"#),
+            (r"x\enspace y",  r#"<mi>x</mi><mspace width="0.5em"/><mi>y</mi>"#),
+            (r"x\negthinspace y", r#"<mi>x</mi><mspace width="-0.1667em"/><mi>y</mi>"#),
             (
                 r"\left\{ x  ( x + 2 ) \right\}", 
                 r#"<mrow><mo stretchy="true" form="prefix">{</mo><mrow><mi>x</mi><mo>(</mo><mi>x</mi><mo>+</mo><mn>2</mn><mo>)</mo></mrow><mo stretchy="true" form="postfix">}</mo></mrow>"#
             ),
             (r"f'", r#"<msup><mi>f</mi><mo>′</mo></msup>"#),
+            (r"x_1 + \dots + x_n", "<msub><mi>x</mi><mn>1</mn></msub><mo>+</mo><mo>⋯</mo><mo>+</mo><msub><mi>x</mi><mi>n</mi></msub>"),
+            (r"x_1, \dots, x_n", "<msub><mi>x</mi><mn>1</mn></msub><mo>,</mo><mo>…</mo><mo>,</mo><msub><mi>x</mi><mi>n</mi></msub>"),
+            (r"a \dotsc \dotsb \iddots", "<mi>a</mi><mo>…</mo><mo>⋯</mo><mo>⋰</mo>"),
+            (
+                r"\begin{matrix} a & b & c \\ \hdotsfor{3} \end{matrix}",
+                r#"<mtable><mtr><mtd><mi>a</mi></mtd><mtd><mi>b</mi></mtd><mtd><mi>c</mi></mtd></mtr><mtr><mtd columnspan="3"><mo>…</mo></mtd></mtr></mtable>"#
+            ),
+            (
+                r"\begin{matrix} a & \hdotsfor[2]{2} \end{matrix}",
+                r#"<mtable><mtr><mtd><mi>a</mi></mtd><mtd columnspan="2"><mo>…</mo></mtd></mtr></mtable>"#
+            ),
+            (
+                r"\begin{matrix} a & \bf \hdotsfor{2} \end{matrix}",
+                r#"<mtable><mtr><mtd><mi>a</mi></mtd><mtd columnspan="2"><mo>…</mo></mtd></mtr></mtable>"#
+            ),
+            (
+                r"\begin{matrix} a & b \over c \\ d & e \end{matrix}",
+                r#"<mtable><mtr><mtd><mi>a</mi></mtd><mtd><mfrac><mi>b</mi><mi>c</mi></mfrac></mtd></mtr><mtr><mtd><mi>d</mi></mtd><mtd><mi>e</mi></mtd></mtr></mtable>"#
+            ),
+            (
+                r"\begin{matrix} a & b \\[4pt] c & d \end{matrix}",
+                r#"<mtable><mtr><mtd><mpadded depth="+4pt"><mi>a</mi></mpadded></mtd><mtd><mpadded depth="+4pt"><mi>b</mi></mpadded></mtd></mtr><mtr><mtd><mi>c</mi></mtd><mtd><mi>d</mi></mtd></mtr></mtable>"#
+            ),
+            (
+                r"\begin{matrix} \frac{a}{b} & c \\[2pt] d & e \end{matrix}",
+                r#"<mtable><mtr><mtd><mpadded depth="+2pt"><mfrac><mi>a</mi><mi>b</mi></mfrac></mpadded></mtd><mtd><mpadded depth="+2pt"><mi>c</mi></mpadded></mtd></mtr><mtr><mtd><mi>d</mi></mtd><mtd><mi>e</mi></mtd></mtr></mtable>"#
+            ),
+            (
+                r"\begin{matrix} a \\ [b] \end{matrix}",
+                r#"<mtable><mtr><mtd><mi>a</mi></mtd></mtr><mtr><mtd><mo>[</mo><mi>b</mi><mo>]</mo></mtd></mtr></mtable>"#
+            ),
+            ("x % comment \\\\ y\n + y", "<mi>x</mi><mo>+</mo><mi>y</mi>"),
+            (r"50\% ~ x", "<mn>50</mn><mi mathvariant=\"normal\">%</mi><mspace width=\"0.3333em\"/><mi>x</mi>"),
+            ("\\text{a % }\n}", "<mtext>a\u{a0}</mtext>"),
+            ("\\text{a%\n  b \\%}", "<mtext>ab %</mtext>"),
+            ("\\text{a~b% comment\n}", "<mtext>a\u{a0}b</mtext>"),
             (
                 r"\begin{pmatrix} x \\ y \end{pmatrix}", 
                 r#"<mrow><mo stretchy="true" form="prefix">(</mo><mtable><mtr><mtd><mi>x</mi></mtd></mtr><mtr><mtd><mi>y</mi></mtd></mtr></mtable><mo stretchy="true" form="postfix">)</mo></mrow>"#
@@ -328,8 +747,89 @@ mod tests {
         ];
 
         for (problem, answer) in problems.iter() {
-            let mathml = convert_content(dbg!(problem)).unwrap();
+            let mathml = convert_content(dbg!(problem), DisplayStyle::Block, Trust::all(), NumberFormat::default()).unwrap();
+            assert_eq!(&mathml, answer);
+        }
+    }
+
+    #[test]
+    fn inline_style() {
+        let problems = [
+            (r"\frac{a}{b}",     "<mfrac><mi>a</mi><mi>b</mi></mfrac>"),
+            (r"\frac{\textstyle a}{b}", r#"<mfrac><mstyle displaystyle="false" scriptlevel="0"><mi>a</mi></mstyle><mi>b</mi></mfrac>"#),
+            (r"\dbinom{n}{k}",    r#"<mstyle displaystyle="true"><mrow><mrow><mo stretchy="true" form="prefix">(</mo><mfrac linethickness="0"><mi>n</mi><mi>k</mi></mfrac><mo stretchy="true" form="postfix">)</mo></mrow></mrow></mstyle>"#),
+            (r"\genfrac{}{}{}{2}{a}{b}", r#"<mstyle displaystyle="false" scriptlevel="1"><mrow><mfrac><mi>a</mi><mi>b</mi></mfrac></mrow></mstyle>"#),
+            (r"a \equiv b \pmod{n}", r#"<mi>a</mi><mo>≡</mo><mi>b</mi><mrow><mspace width="0.4444em"/><mo>(</mo><mo lspace="0em" rspace="0em">mod</mo><mspace width="0.3333em"/><mi>n</mi><mo>)</mo></mrow>"#),
+            (r"a \equiv b \mod n", r#"<mi>a</mi><mo>≡</mo><mi>b</mi><mrow><mspace width="0.6667em"/><mo lspace="0em" rspace="0em">mod</mo><mspace width="0.3333em"/><mi>n</mi></mrow>"#),
+        ];
+
+        for (problem, answer) in problems.iter() {
+            let mathml = convert_content(dbg!(problem), DisplayStyle::Inline, Trust::default(), NumberFormat::default()).unwrap();
+            assert_eq!(&mathml, answer);
+        }
+    }
+
+    #[test]
+    fn untrusted() {
+        let problems = [
+            (r"\href{https://example.com}{x}", "<mi>x</mi>"),
+            (r"\url{https://example.com}", "<mtext>https://example.com</mtext>"),
+            (r"\class{foo}{x}", "<mi>x</mi>"),
+            (r"\htmlData{foo=bar}{x}", "<mi>x</mi>"),
+            (r#"\style{color: red}{x}"#, "<mi>x</mi>"),
+            (r#"\enclose{circle}[mathcolor="red", style="border: 1px"]{x}"#, r#"<menclose notation="circle" mathcolor="red"><mi>x</mi></menclose>"#),
+            (r"\bbox[yellow,border:1px solid red]{x}", r##"<mpadded mathbackground="#ffff00"><mi>x</mi></mpadded>"##),
+            (
+                r"\enclose{box}[data-secret=1, tabindex=0, autofocus=x, xmlns=evil, mathcolor=red]{x}",
+                r#"<menclose notation="box" mathcolor="red"><mi>x</mi></menclose>"#
+            ),
+        ];
+
+        for (problem, answer) in problems.iter() {
+            let mathml = convert_content(dbg!(problem), DisplayStyle::Block, Trust::none(), NumberFormat::default()).unwrap();
             assert_eq!(&mathml, answer);
         }
     }
+
+    #[test]
+    fn untrusted_by_default() {
+        let mathml = super::latex_to_mathml(r"\href{https://example.com}{x} \style{position:fixed}{y}", DisplayStyle::Inline).unwrap();
+        assert!(!mathml.contains("href") && !mathml.contains("style"));
+        let html = super::replace(r"$\class{foo}{x}$").unwrap();
+        assert!(!html.contains("class"));
+    }
+
+    #[test]
+    fn options() {
+        let options = super::Options {
+            trust: Trust { href: true, ..Trust::none() },
+            number_format: NumberFormat { grouping: true, ..NumberFormat::default() },
+        };
+        let html = super::replace_with_options(r"$\href{https://example.com}{1\,000}$", options).unwrap();
+        assert!(html.contains(r#"href="https://example.com""#));
+        assert!(html.contains("<mn>1\u{2009}000</mn>"));
+    }
+
+    #[test]
+    fn ambiguous_fraction() {
+        let problems = [r"{a \over b \over c}", r"a \choose b \atop c"];
+
+        for problem in problems.iter() {
+            assert!(convert_content(problem, DisplayStyle::Block, Trust::default(), NumberFormat::default()).is_err());
+        }
+    }
+
+    #[test]
+    fn misused_hdotsfor() {
+        let problems = [
+            r"\begin{matrix} a & \hdotsfor{x} \end{matrix}",
+            r"\begin{matrix} a & \hdotsfor{0} \end{matrix}",
+            r"\begin{matrix} a & {\hdotsfor{2}} \end{matrix}",
+            r"\hdotsfor{2}",
+        ];
+
+        for problem in problems.iter() {
+            assert!(convert_content(problem, DisplayStyle::Block, Trust::default(), NumberFormat::default()).is_err());
+        }
+    }
 }
@@ -1,9 +1,12 @@
 use super::{
-    attribute::{Variant, Accent, LineThickness, ColumnAlign},
-    token::Token, 
+    attribute::{Variant, LineThickness, FracAttr, ColumnAlign, MathStyle, MathClass, Length, LengthUnit},
+    token::{Token, TextToken}, 
     lexer::Lexer,
     ast::Node,
     error::LatexError,
+    color::parse_color,
+    text::{text_symbol, text_accent, compose},
+    DisplayStyle, Trust,
 };
 
 #[derive(Debug, Clone)]
@@ -11,19 +14,43 @@ pub(crate) struct Parser<'a> {
     l: Lexer<'a>,
     cur_token: Token,
     peek_token: Token,
+    /// 現在のスタイル (分数や添字の中で変化する)
+    style: MathStyle,
+    /// `\mathbf` や `\bf` などで指定された現在のフォント
+    font: Option<Variant>,
+    /// `\boldsymbol` の中かどうか
+    bold: bool,
+    /// `\textbf` などで指定された現在のテキストのフォント
+    text_font: Variant,
+    /// HTML の属性を出力するコマンドを許可するかどうか
+    trust: Trust,
+    /// 次の `parse_sequence()` が行列の中身を直接読むかどうか
+    cell: bool,
 }
 impl<'a> Parser<'a> {
-    pub(crate) fn new(l: Lexer<'a>) -> Self {
+    pub(crate) fn new(l: Lexer<'a>, display: DisplayStyle, trust: Trust) -> Self {
         let mut p = Parser { 
             l, 
             cur_token: Token::Illegal('\u{0}'),
             peek_token: Token::Illegal('\u{0}'),
+            style: MathStyle::from(display),
+            font: None,
+            bold: false,
+            text_font: Variant::Normal,
+            trust,
+            cell: false,
         };
         p.next_token();
         p.next_token();
         p
     }
 
+    /// 本来の字形が `var` の文字に, 現在のフォントを適用したフォントを返す.
+    fn variant(&self, var: Variant) -> Variant {
+        let var = self.font.unwrap_or(var);
+        if self.bold { bold_variant(var) } else { var }
+    }
+
     fn next_token(&mut self) {
         self.cur_token = self.peek_token.clone();
         self.peek_token = if 
@@ -46,32 +73,139 @@ impl<'a> Parser<'a> {
     }
 
     pub(crate) fn parse(&mut self) -> Result<Vec<Node>, LatexError> {
+        self.parse_sequence(&Token::EOF, false)
+    }
+
+    /// `end_token` が現れるまでノードを読み込む.
+    /// 
+    /// 終了時は `cur_token` が `end_token` を指す. `in_cell` が真の場合は
+    /// 行列のセルの区切り `&`, `\\` でも終了する.
+    fn parse_sequence(&mut self, end_token: &Token, in_cell: bool) -> Result<Vec<Node>, LatexError> {
         let mut nodes = Vec::new();
+        let mut infix = None;
+        // `\displaystyle` などで指定したスタイル, 外側のスタイル, 作用し始めたノードの位置
+        let mut pending = None;
+        // 現在のセルの先頭のノードの位置
+        let mut start = 0;
+        // `\hdotsfor` は行列のセルの直下でのみ使える
+        let cell = std::mem::replace(&mut self.cell, false);
 
-        while !self.cur_token_is(&Token::EOF) {
-            nodes.push(
-                self.parse_node()?
-            );
-            self.next_token();
+        loop {
+            if self.cur_token_is(end_token) 
+                || (in_cell && (self.cur_token_is(&Token::Ampersand) || self.cur_token_is(&Token::NewLine))) 
+            {
+                break;
+            }
+            if self.cur_token_is(&Token::EOF) { // 閉じ括弧がないまま入力が終了した場合
+                return Err(LatexError::UnexpectedToken{
+                    expected: end_token.clone(),
+                    got: self.cur_token.clone()
+                });
+            }
+
+            match &self.cur_token {
+                Token::InfixFrac(open, close, lt) => {
+                    let (open, close, lt) = (*open, *close, *lt);
+                    // `{a \over b}` のような中置形式の分数
+                    // 注) TeX と同様に `{a \over b \over c}` はエラーとする
+                    if infix.is_some() {
+                        return Err(LatexError::AmbiguousFraction(self.cur_token.clone()));
+                    }
+                    // 分子の中で指定したスタイルは分母には作用しない
+                    self.end_style(&mut nodes, &mut pending);
+                    infix = Some((open, close, lt, nodes.split_off(start)));
+                    self.next_token();
+                },
+                Token::MathStyle(style) => {
+                    // `\displaystyle` などはグループの終わりか中置形式の分数まで作用する
+                    let style = *style;
+                    self.next_token();
+                    self.end_style(&mut nodes, &mut pending);
+                    let outer = std::mem::replace(&mut self.style, style);
+                    pending = Some((style, outer, nodes.len()));
+                },
+                Token::Font(var) => {
+                    // `\bf` などもグループの終わりまで作用する
+                    let var = *var;
+                    self.next_token();
+                    let outer = self.font.replace(var);
+                    self.cell = cell;
+                    let content = self.parse_sequence(end_token, true);
+                    self.font = outer;
+                    nodes.extend(content?);
+                },
+                Token::Color => {
+                    // `\color{red}` もグループの終わりまで作用する
+                    let color = self.parse_color_argument()?;
+                    self.next_token();
+                    let content = into_row(self.parse_sequence(end_token, true)?);
+                    nodes.push(Node::Color(color, Box::new(content)));
+                },
+                Token::HDotsFor => {
+                    if !cell {
+                        return Err(LatexError::OutsideMatrix(Token::HDotsFor));
+                    }
+                    // `\hdotsfor[1.5]{3}` の点の間隔は無視する
+                    self.parse_optional_raw_argument()?;
+                    let arg = self.parse_raw_argument()?;
+                    let n = match arg.trim().parse::<usize>() {
+                        Ok(n) if n > 0 => n,
+                        _ => return Err(LatexError::InvalidArgument(Token::HDotsFor, arg)),
+                    };
+                    // `columnspan` を付けられるようにスタイルの作用の外に置く
+                    let style = pending.map(|(style, _, _)| style);
+                    self.end_style(&mut nodes, &mut pending);
+                    nodes.push(Node::HDotsFor(n));
+                    if let Some(style) = style {
+                        let outer = std::mem::replace(&mut self.style, style);
+                        pending = Some((style, outer, nodes.len()));
+                    }
+                    self.next_token();
+                },
+                Token::Limits(_) => {
+                    // 先頭の `\limits`, `\nolimits` も無視する
+                    self.next_token();
+                },
+                Token::Ampersand | Token::NewLine => {
+                    // 行列のセルの区切りでもスタイルの作用と中置形式の分数は終わる
+                    self.end_style(&mut nodes, &mut pending);
+                    end_infix(&mut nodes, &mut infix, start);
+                    nodes.push(self.parse_node()?);
+                    start = nodes.len();
+                    self.next_token();
+                },
+                _ => {
+                    nodes.push(
+                        self.parse_node()?
+                    );
+                    self.next_token();
+                },
+            }
         }
 
+        self.end_style(&mut nodes, &mut pending);
+        end_infix(&mut nodes, &mut infix, start);
         Ok(nodes)
     }
 
     fn parse_node(&mut self) -> Result<Node, LatexError> {
         let left = self.parse_single_node()?;
+        // 大型演算子以外の後の `\limits`, `\nolimits` は無視する
+        while let Token::Limits(_) = self.peek_token {
+            self.next_token();
+        }
 
         match self.peek_token {
             Token::Underscore => {
                 self.next_token();
                 self.next_token();
-                let right = self.parse_node()?;
+                let right = self.parse_node_in(self.style.script())?;
                 Ok(Node::Subscript(Box::new(left), Box::new(right)))
             }
             Token::Circumflex => {
                 self.next_token();
                 self.next_token();
-                let right = self.parse_node()?;
+                let right = self.parse_node_in(self.style.script())?;
                 Ok(Node::Superscript(Box::new(left), Box::new(right)))
             },
             _ => Ok(left),
@@ -83,69 +217,148 @@ impl<'a> Parser<'a> {
     // 注) 中置演算子を考慮して正しくノードを読む場合は `parse_node()` を使う.
     fn parse_single_node(&mut self) -> Result<Node, LatexError> {
         let node = match &self.cur_token {
-            Token::Number(number) => Node::Number(number.clone()),
-            Token::Letter(x, v)   => Node::Letter(*x, *v),
-            Token::Operator(op) => Node::Operator(*op),
-            Token::Function(fun)  => Node::Function(fun.to_string(), None),
+            Token::Number(number) => Node::Number(number.clone(), self.variant(Variant::Normal)),
+            Token::Letter(x, v)   => Node::Letter(*x, self.variant(*v)),
+            Token::Operator('∂') => Node::Operator('∂', self.variant(Variant::Italic)),
+            Token::Operator(op) => Node::Operator(*op, self.variant(Variant::Normal)),
+            Token::Symbol(c) => Node::Text(c.to_string(), Variant::Normal),
+            Token::Dots => {
+                // amsmath と同様に, 直後が演算子なら `\cdots`, それ以外なら `\ldots` にする
+                let op = match &self.peek_token {
+                    Token::Operator(op) if !matches!(op, ',' | '.' | ';' | ':' | '!' | '?') => '⋯',
+                    Token::BigOp(_) | Token::Integral(_) => '⋯',
+                    _ => '…',
+                };
+                Node::Operator(op, self.variant(Variant::Normal))
+            },
+            Token::HDotsFor => { return Err(LatexError::OutsideMatrix(Token::HDotsFor)); },
+            Token::Function(fun)  => Node::Function(fun.to_string(), self.variant(Variant::Normal), None),
             Token::Space(space) => Node::Space(*space),
             Token::Sqrt => {
                 self.next_token();
                 let degree = if self.cur_token_is(&Token::Paren("[")) {
-                    let degree = self.parse_group(&Token::Paren("]"))?;
+                    let outer = std::mem::replace(&mut self.style, MathStyle::ScriptScript);
+                    let degree = self.parse_group(&Token::Paren("]"));
+                    self.style = outer;
+                    let degree = degree?;
                     self.next_token();
                     Some(Box::new(degree))
                 } else { None };
                 let content = self.parse_node()?;
                 Node::Sqrt(degree, Box::new(content))
             },
-            Token::Frac => {
+            Token::Frac(style) => {
+                let style = *style;
+                let inner = style.unwrap_or(self.style).fraction();
                 self.next_token();
-                let numerator = self.parse_node()?;
+                let numerator = self.parse_node_in(inner)?;
                 self.next_token();
-                let denominator = self.parse_node()?;
-                Node::Frac(Box::new(numerator), Box::new(denominator), LineThickness::Medium)
+                let denominator = self.parse_node_in(inner)?;
+
+                let frac = Node::Frac(Box::new(numerator), Box::new(denominator), LineThickness::Medium, FracAttr::Normal);
+                match style {
+                    Some(style) => self.styled(style, Node::Row(vec![frac])),
+                    None        => frac
+                }
             },
-            Token::Binom(display) => {
-                let display = *display;
+            Token::CFrac => {
+                let attr = match self.parse_optional_raw_argument()?.as_deref() {
+                    Some("l") => FracAttr::NumAlignLeft,
+                    Some("r") => FracAttr::NumAlignRight,
+                    _ => FracAttr::Normal,
+                };
+                let inner = MathStyle::Display.fraction();
                 self.next_token();
-                let numerator = self.parse_node()?;
+                let numerator = self.parse_node_in(inner)?;
                 self.next_token();
-                let denominator = self.parse_node()?;
+                let denominator = self.parse_node_in(inner)?;
+
+                let frac = Node::Frac(Box::new(numerator), Box::new(denominator), LineThickness::Medium, attr);
+                self.styled(MathStyle::Display, Node::Row(vec![frac]))
+            },
+            Token::GenFrac => {
+                // `\genfrac{左括弧}{右括弧}{線の太さ}{スタイル}{分子}{分母}`
+                let open = delimiter(&self.parse_raw_argument()?);
+                let close = delimiter(&self.parse_raw_argument()?);
+                let lt = match Length::parse(&self.parse_raw_argument()?) {
+                    Some(thickness) => LineThickness::Dimension(thickness),
+                    None => LineThickness::Medium,
+                };
+                let style = match self.parse_raw_argument()?.trim() {
+                    "0" => Some(MathStyle::Display),
+                    "1" => Some(MathStyle::Text),
+                    "2" => Some(MathStyle::Script),
+                    "3" => Some(MathStyle::ScriptScript),
+                    _ => None,
+                };
+                let inner = style.unwrap_or(self.style).fraction();
+                self.next_token();
+                let numerator = self.parse_node_in(inner)?;
+                self.next_token();
+                let denominator = self.parse_node_in(inner)?;
+
+                let frac = Node::Frac(Box::new(numerator), Box::new(denominator), lt, FracAttr::Normal);
+                let frac = if open.is_empty() && close.is_empty() {
+                    frac
+                } else {
+                    Node::Fenced{ open, close, content: Box::new(frac) }
+                };
+                match style {
+                    Some(style) => self.styled(style, Node::Row(vec![frac])),
+                    None        => frac
+                }
+            },
+            Token::SFrac => {
+                let inner = self.style.fraction();
+                self.next_token();
+                let numerator = self.parse_node_in(inner)?;
+                self.next_token();
+                let denominator = self.parse_node_in(inner)?;
+                Node::Frac(Box::new(numerator), Box::new(denominator), LineThickness::Medium, FracAttr::Bevelled)
+            },
+            Token::Binom(style) => {
+                let style = *style;
+                let inner = style.unwrap_or(self.style).fraction();
+                self.next_token();
+                let numerator = self.parse_node_in(inner)?;
+                self.next_token();
+                let denominator = self.parse_node_in(inner)?;
 
                 let binom = Node::Fenced {
                     open: "(",
                     close: ")",
                     content: Box::new(
-                        Node::Frac(Box::new(numerator), Box::new(denominator), LineThickness::Length(0))
+                        Node::Frac(Box::new(numerator), Box::new(denominator), LineThickness::Length(0), FracAttr::Normal)
                     ),
                 };
-                match display {
-                    Some(display) => Node::Style(Some(display), Box::new(Node::Row(vec![binom]))),
-                    None          => binom
+                match style {
+                    Some(style) => self.styled(style, Node::Row(vec![binom])),
+                    None        => binom
                 }
             },
-            Token::Over(op, acc) => {
-                let (op, acc) = (*op, *acc);
+            Token::Over(op, acc, stretchy) => {
+                let (op, acc, stretchy) = (*op, *acc, *stretchy);
                 self.next_token();
-                let target = self.parse_node()?;
-                Node::OverOp(op, acc, Box::new(target))
+                // `\hat{x}^2` の添字はアクセントの付いた `x` に付く
+                let target = self.parse_single_node()?;
+                Node::OverOp(op, acc, stretchy, Box::new(target))
             },
-            Token::Under(op, acc) => {
-                let (op, acc) = (*op, *acc);
+            Token::Under(op, acc, stretchy) => {
+                let (op, acc, stretchy) = (*op, *acc, *stretchy);
                 self.next_token();
-                let target = self.parse_node()?;
-                Node::UnderOp(op, acc, Box::new(target))
+                let target = self.parse_single_node()?;
+                Node::UnderOp(op, acc, stretchy, Box::new(target))
             },
             Token::Overset => {
                 self.next_token();
-                let over = self.parse_node()?;
+                let over = self.parse_node_in(self.style.script())?;
                 self.next_token();
                 let target = self.parse_node()?;
                 Node::Overset{over: Box::new(over), target: Box::new(target)}
             },
             Token::Underset => {
                 self.next_token();
-                let under = self.parse_node()?;
+                let under = self.parse_node_in(self.style.script())?;
                 self.next_token();
                 let target = self.parse_node()?;
                 Node::Underset{under: Box::new(under), target: Box::new(target)}
@@ -157,11 +370,11 @@ impl<'a> Parser<'a> {
                 if self.peek_token_is(Token::Circumflex) {
                     self.next_token();
                     self.next_token();
-                    let expl = self.parse_single_node()?;
-                    let over = Node::Overset{over: Box::new(expl), target: Box::new(Node::Operator(x))};
+                    let expl = self.parse_single_node_in(self.style.script())?;
+                    let over = Node::Overset{over: Box::new(expl), target: Box::new(Node::Operator(x, Variant::Normal))};
                     Node::Overset{over: Box::new(over), target: Box::new(target)}
                 } else {
-                    Node::Overset{over: Box::new(Node::Operator(x)), target: Box::new(target)}
+                    Node::Overset{over: Box::new(Node::Operator(x, Variant::Normal)), target: Box::new(target)}
                 }
             },
             Token::Underbrace(x) => {
@@ -171,54 +384,34 @@ impl<'a> Parser<'a> {
                 if self.peek_token_is(Token::Underscore) {
                     self.next_token();
                     self.next_token();
-                    let expl = self.parse_single_node()?;
-                    let under = Node::Underset{under: Box::new(expl), target: Box::new(Node::Operator(x))};
+                    let expl = self.parse_single_node_in(self.style.script())?;
+                    let under = Node::Underset{under: Box::new(expl), target: Box::new(Node::Operator(x, Variant::Normal))};
                     Node::Underset{under: Box::new(under), target: Box::new(target)}
                 } else {
-                    Node::Underset{under: Box::new(Node::Operator(x)), target: Box::new(target)}
+                    Node::Underset{under: Box::new(Node::Operator(x, Variant::Normal)), target: Box::new(target)}
                 }
             },
             Token::BigOp(op) => {
-                let op = *op;
-                match self.peek_token {
-                    Token::Underscore => {
-                        self.next_token();
-                        self.next_token();
-                        let under = self.parse_single_node()?;
-                        if self.peek_token_is(Token::Circumflex) {
-                            self.next_token();
-                            self.next_token();
-                            let over = self.parse_single_node()?;
-                            Node::UnderOver{ target: Box::new(Node::Operator(op)), under: Box::new(under), over: Box::new(over) }
-                        } else {
-                            Node::Under(Box::new(Node::Operator(op)), Box::new(under))
-                        }
-                    },
-                    Token::Circumflex => {
-                        self.next_token();
-                        self.next_token();
-                        let over = self.parse_single_node()?;
-                        if self.peek_token_is(Token::Underscore) {
-                            self.next_token();
-                            self.next_token();
-                            let under = self.parse_single_node()?;
-                            Node::UnderOver{ target: Box::new(Node::Operator(op)), under: Box::new(under), over: Box::new(over) }
-                        } else {
-                            Node::OverOp(op, Accent::False, Box::new(over))
-                        }
-                    },
-                    _ => Node::Operator(op)
+                let op = Node::Operator(*op, Variant::Normal);
+                // `\nolimits` なら右下, 右上に添字を置く
+                match self.parse_limits_token() {
+                    Some(false) => self.parse_scripts(op)?,
+                    _ => self.parse_limits(op)?,
                 }
             },
             Token::Lim(lim) => {
-                let lim = Node::Function(lim.to_string(), None);
-                if self.peek_token_is(Token::Underscore) {
-                    self.next_token();
-                    self.next_token();
-                    let under = self.parse_single_node()?;
-                    Node::Under(Box::new(lim), Box::new(under))
-                } else {
-                    lim
+                let lim = Node::Function(lim.to_string(), self.variant(Variant::Normal), None);
+                match self.parse_limits_token() {
+                    Some(true) => self.parse_limits(lim)?,
+                    Some(false) => self.parse_scripts(lim)?,
+                    None => if self.peek_token_is(Token::Underscore) {
+                        self.next_token();
+                        self.next_token();
+                        let under = self.parse_single_node_in(self.style.script())?;
+                        Node::Under(Box::new(lim), Box::new(under))
+                    } else {
+                        lim
+                    },
                 }
             },
             Token::Slashed => {
@@ -228,84 +421,75 @@ impl<'a> Parser<'a> {
                 self.next_token();
                 Node::Slashed(Box::new(node))
             },
+            Token::Not => {
+                self.next_token();
+                let op = match &self.cur_token {
+                    Token::Operator(op) => Some(*op),
+                    Token::Paren(paren) if paren.chars().count() == 1 => paren.chars().next(),
+                    _ => None,
+                };
+                match op {
+                    Some(op) => match negated(op) {
+                        Some(neg) => Node::Operator(neg, Variant::Normal),
+                        None => Node::Slashed(Box::new(Node::Operator(op, Variant::Normal))),
+                    },
+                    None => Node::Slashed(Box::new(self.parse_single_node()?)),
+                }
+            },
             Token::Style(var) => {
                 let var = *var;
                 self.next_token();
-                let node = self.parse_node()?;
-                set_variant(node, var)
+                // 添字はフォントを戻してから `parse_node()` で付ける
+                let outer = self.font.replace(var);
+                let node = self.parse_single_node();
+                self.font = outer;
+                node?
+            },
+            Token::BoldSymbol => {
+                // `\boldsymbol` は字形を変えずに太字にする
+                self.next_token();
+                let outer = std::mem::replace(&mut self.bold, true);
+                let node = self.parse_single_node();
+                self.bold = outer;
+                node?
             },
             Token::Integral(int) => {
-                let int = *int;
-                match self.peek_token {
-                    Token::Underscore => {
-                        self.next_token();
-                        self.next_token();
-                        let sub = self.parse_single_node()?;
-                        if self.peek_token_is(Token::Circumflex) {
-                            self.next_token();
-                            self.next_token();
-                            let sup = self.parse_single_node()?;
-                            Node::SubSup{ target: Box::new(Node::Operator(int)), sub: Box::new(sub), sup: Box::new(sup) }
-                        } else {
-                            Node::Subscript(Box::new(Node::Operator(int)), Box::new(sub))
-                        }
-                    },
-                    Token::Circumflex => {
-                        self.next_token();
-                        self.next_token();
-                        let sup = self.parse_single_node()?;
-                        if self.peek_token_is(Token::Underscore) {
-                            self.next_token();
-                            self.next_token();
-                            let sub = self.parse_single_node()?;
-                            Node::SubSup{ target: Box::new(Node::Operator(int)), sub: Box::new(sub), sup: Box::new(sup) }
-                        } else {
-                            Node::Superscript(Box::new(Node::Operator(int)), Box::new(sup))
-                        }
-                    },
-                    _ => Node::Operator(int)
+                let int = Node::Operator(*int, Variant::Normal);
+                // `\limits` なら上下に添字を置く
+                match self.parse_limits_token() {
+                    Some(true) => self.parse_limits(int)?,
+                    _ => self.parse_scripts(int)?,
                 }
             },
             Token::LBrace => self.parse_group(&Token::RBrace)?,
             Token::Paren(paren) => Node::OtherOperator(paren),
             Token::Left => {
                 self.next_token();
-                let open = match &self.cur_token {
-                    Token::Paren(open) => *open,
-                    Token::Operator('.') => "",
-                    token => {return Err(LatexError::MissingParensethis{
-                        location: Token::Left, got: token.clone(),
-                    })},
-                };
+                let open = delimiter_of(&self.cur_token).ok_or_else(|| LatexError::MissingParensethis{
+                    location: Token::Left, got: self.cur_token.clone(),
+                })?;
                 let content = self.parse_group(&Token::Right)?;
                 self.next_token();
-                let close = match &self.cur_token {
-                    Token::Paren(close) => close,
-                    Token::Operator('.') => "",
-                    token => {return Err(LatexError::MissingParensethis{
-                        location: Token::Right, got: token.clone(),
-                    })},
-                };
+                let close = delimiter_of(&self.cur_token).ok_or_else(|| LatexError::MissingParensethis{
+                    location: Token::Right, got: self.cur_token.clone(),
+                })?;
                 Node::Fenced{open, close, content: Box::new(content)}
             },
             Token::Middle => {
                 let stretchy = true;
                 self.next_token();
-                match self.parse_single_node()? {
-                    Node::Operator(op) => Node::StrechedOp(stretchy, op.to_string()),
-                    Node::OtherOperator(op) => Node::StrechedOp(stretchy, op.to_owned()),
-                    _ => unimplemented!()
-                }
+                let op = delimiter_of(&self.cur_token).ok_or_else(|| LatexError::MissingParensethis{
+                    location: Token::Middle, got: self.cur_token.clone(),
+                })?;
+                Node::StrechedOp(stretchy, op.to_owned())
             },
-            Token::Big(size) => {
-                let size = *size;
+            Token::Big(size, form) => {
+                let (size, form) = (*size, *form);
                 self.next_token();
-                match self.cur_token {
-                    Token::Paren(paren) => Node::SizedParen{ size, paren },
-                    _ => {return Err(LatexError::UnexpectedToken{
-                        expected: Token::Paren(""), got: self.cur_token.clone(),
-                    });},
-                }
+                let paren = delimiter_of(&self.cur_token).ok_or_else(|| LatexError::MissingParensethis{
+                    location: Token::Big(size, form), got: self.cur_token.clone(),
+                })?;
+                Node::SizedParen{ size, form, paren }
             },
             Token::Begin => {
                 self.next_token();
@@ -315,7 +499,10 @@ impl<'a> Parser<'a> {
                     (ColumnAlign::Left, "matrix".to_owned())
                 } else { (ColumnAlign::Center, environment) };
                 // \begin..\end の中身を読み込む
-                let content = match self.parse_group(&Token::End)? {
+                let outer = std::mem::replace(&mut self.cell, true);
+                let content = self.parse_group(&Token::End);
+                self.cell = outer;
+                let content = match content? {
                     Node::Row(content) => content,
                     content => vec![content],
                 };
@@ -338,23 +525,258 @@ impl<'a> Parser<'a> {
                 self.next_token();
                 // 関数名を読み込む
                 let function = self.parse_text();
-                Node::Function(function, None)
+                Node::Function(function, self.variant(Variant::Normal), None)
+            },
+            Token::Bmod => {
+                // amsmath と同じく前後に 5mu の空白を置く
+                Node::Row(vec![Node::Space(Length::mu(5.)), mod_operator(), Node::Space(Length::mu(5.))])
+            },
+            Token::Pmod | Token::Pod | Token::Mod => {
+                let token = self.cur_token.clone();
+                self.next_token();
+                let arg = self.parse_single_node()?;
+                // 前の空白は display style で 18mu, それ以外では `\mod` が 12mu, `\pmod`, `\pod` が 8mu
+                let space = match (self.style, &token) {
+                    (MathStyle::Display, _) => Length::mu(18.),
+                    (_, Token::Mod)         => Length::mu(12.),
+                    _                       => Length::mu(8.),
+                };
+                let mut nodes = vec![Node::Space(space)];
+                if token != Token::Mod {
+                    nodes.push(Node::Operator('(', Variant::Normal));
+                }
+                if token != Token::Pod {
+                    nodes.push(mod_operator());
+                    nodes.push(Node::Space(Length::mu(6.)));
+                }
+                nodes.push(arg);
+                if token != Token::Mod {
+                    nodes.push(Node::Operator(')', Variant::Normal));
+                }
+                Node::Row(nodes)
+            },
+            Token::MathClass(class) => {
+                let class = *class;
+                self.next_token();
+                let content = Box::new(self.parse_single_node()?);
+                // `\limits`, `\nolimits` は `\mathop` の直後のみ有効
+                let limits = match self.peek_token {
+                    Token::Limits(limits) if class == MathClass::Op => {
+                        self.next_token();
+                        Some(limits)
+                    },
+                    _ => None,
+                };
+                let movable = class == MathClass::Op && content.operator_text().is_some();
+                match limits {
+                    // `\limits` なら常に上下に添字を置く
+                    Some(true) => {
+                        let movablelimits = if movable { Some(false) } else { None };
+                        self.parse_limits(Node::Atom{ class, movablelimits, content })?
+                    },
+                    // 指定がなければ `<mo>` に書ける場合のみ上下に置き, インライン数式では右下に動かす
+                    None if movable => {
+                        self.parse_limits(Node::Atom{ class, movablelimits: Some(true), content })?
+                    },
+                    _ => Node::Atom{ class, movablelimits: None, content },
+                }
+            },
+            Token::Text(font) => {
+                let var = match font {
+                    Some(font) => text_variant(self.text_font, *font),
+                    None       => self.text_font,
+                };
+                let text = self.parse_raw_argument()?;
+                self.parse_text_mode(&text, var)?
+            },
+            Token::Emph => {
+                let var = emph_variant(self.text_font);
+                let text = self.parse_raw_argument()?;
+                self.parse_text_mode(&text, var)?
+            },
+            Token::Enclose(notation) => {
+                let notation = notation.to_string();
+                self.next_token();
+                let content = self.parse_single_node()?;
+                Node::Enclose{ notation, attrs: Vec::new(), content: Box::new(content) }
+            },
+            Token::GenEnclose => {
+                // `\enclose{notation}[attributes]{content}`
+                let notation = self.parse_raw_argument()?.trim().to_owned();
+                let attrs = match self.parse_optional_raw_argument()? {
+                    Some(attrs) => enclose_attributes(&attrs, self.trust),
+                    None => Vec::new(),
+                };
+                self.next_token();
+                let content = self.parse_single_node()?;
+                Node::Enclose{ notation, attrs, content: Box::new(content) }
+            },
+            Token::CancelTo => {
+                // `\cancelto{value}{content}` は矢印で消した `content` の右上に `value` を置く
+                self.next_token();
+                let value = self.parse_node_in(self.style.script())?;
+                self.next_token();
+                let content = self.parse_single_node()?;
+                let cancel = Node::Enclose{ notation: "updiagonalarrow".to_owned(), attrs: Vec::new(), content: Box::new(content) };
+                Node::Superscript(Box::new(cancel), Box::new(value))
+            },
+            Token::Phantom => {
+                self.next_token();
+                let content = self.parse_single_node()?;
+                Node::Phantom(Box::new(content))
+            },
+            Token::HPhantom => {
+                self.next_token();
+                let content = self.parse_single_node()?;
+                Node::Padded{
+                    attrs: vec![("height", "0".to_owned()), ("depth", "0".to_owned())],
+                    content: Box::new(Node::Phantom(Box::new(content))),
+                }
+            },
+            Token::VPhantom => {
+                self.next_token();
+                let content = self.parse_single_node()?;
+                Node::Padded{
+                    attrs: vec![("width", "0".to_owned())],
+                    content: Box::new(Node::Phantom(Box::new(content))),
+                }
+            },
+            Token::MathStrut => Node::Padded{
+                attrs: vec![("width", "0".to_owned())],
+                content: Box::new(Node::Phantom(Box::new(Node::Operator('(', Variant::Normal)))),
+            },
+            Token::Strut => Node::Padded{
+                attrs: vec![
+                    ("width", "0".to_owned()),
+                    ("height", "8.6pt".to_owned()),
+                    ("depth", "3pt".to_owned()),
+                ],
+                content: Box::new(Node::Row(Vec::new())),
+            },
+            Token::Smash => {
+                // `\smash[t]` は高さのみ, `\smash[b]` は深さのみを 0 にする
+                let attrs = match self.parse_optional_raw_argument()?.as_deref().map(str::trim) {
+                    Some("t") => vec![("height", "0".to_owned())],
+                    Some("b") => vec![("depth", "0".to_owned())],
+                    _ => vec![("height", "0".to_owned()), ("depth", "0".to_owned())],
+                };
+                self.next_token();
+                let content = self.parse_single_node()?;
+                Node::Padded{ attrs, content: Box::new(content) }
+            },
+            Token::Lap(lspace) => {
+                let mut attrs = vec![("width", "0".to_owned())];
+                if let Some(lspace) = lspace {
+                    attrs.push(("lspace", lspace.to_string()));
+                }
+                self.next_token();
+                let content = self.parse_single_node()?;
+                Node::Padded{ attrs, content: Box::new(content) }
+            },
+            Token::Href => {
+                let url = self.parse_url_argument()?;
+                self.next_token();
+                let content = self.parse_single_node()?;
+                if self.trust.href && is_safe_url(&url) {
+                    Node::Html{ attrs: vec![("href".to_owned(), url)], content: Box::new(content) }
+                } else {
+                    content
+                }
             },
-            Token::Text => {
+            Token::Url => {
+                let url = self.parse_url_argument()?;
+                let content = Node::Text(url.clone(), Variant::Normal);
+                if self.trust.href && is_safe_url(&url) {
+                    Node::Html{ attrs: vec![("href".to_owned(), url)], content: Box::new(content) }
+                } else {
+                    content
+                }
+            },
+            Token::Html(attr) => {
+                let attr = *attr;
+                let value = self.parse_raw_argument()?;
+                self.next_token();
+                let content = self.parse_single_node()?;
+                let trusted = match attr {
+                    "class" => self.trust.class,
+                    "id"    => self.trust.id,
+                    _       => self.trust.style,
+                };
+                if trusted {
+                    Node::Html{ attrs: vec![(attr.to_owned(), value.trim().to_owned())], content: Box::new(content) }
+                } else {
+                    content
+                }
+            },
+            Token::HtmlData => {
+                // `\htmlData{key=value, ..}{content}`
+                let attrs = data_attributes(&self.parse_raw_argument()?);
+                self.next_token();
+                let content = self.parse_single_node()?;
+                if self.trust.data {
+                    Node::Html{ attrs, content: Box::new(content) }
+                } else {
+                    content
+                }
+            },
+            Token::TextColor => {
+                let color = self.parse_color_argument()?;
+                self.next_token();
+                let content = self.parse_single_node()?;
+                Node::Color(color, Box::new(content))
+            },
+            Token::ColorBox => {
+                let background = self.parse_color_argument()?;
+                let text = self.parse_raw_argument()?;
+                let mut attrs = vec![("mathbackground", background)];
+                attrs.extend(padding_attributes(FBOXSEP));
+                Node::Padded{ attrs, content: Box::new(self.parse_text_mode(&text, self.text_font)?) }
+            },
+            Token::FColorBox => {
+                let frame = self.parse_color_argument()?;
+                let background = self.parse_color_argument()?;
+                let text = self.parse_raw_argument()?;
+                let mut attrs = vec![
+                    ("mathbackground", background),
+                    ("style", format!("border: 0.4pt solid {}", frame)),
+                ];
+                attrs.extend(padding_attributes(FBOXSEP));
+                Node::Padded{ attrs, content: Box::new(self.parse_text_mode(&text, self.text_font)?) }
+            },
+            Token::Fbox => {
+                // `\framebox[width][pos]{text}` の幅と位置は無視する
+                while self.parse_optional_raw_argument()?.is_some() {}
+                let text = self.parse_raw_argument()?;
+                Node::Enclose{ notation: "box".to_owned(), attrs: Vec::new(), content: Box::new(self.parse_text_mode(&text, self.text_font)?) }
+            },
+            Token::BBox => {
+                // `\bbox[color,padding]{content}`
+                let options = self.parse_optional_raw_argument()?.unwrap_or_default();
                 self.next_token();
-                // テキストを読み込む
-                let text = self.parse_text();
-                Node::Text(text)
+                let content = self.parse_single_node()?;
+                Node::Padded{ attrs: bbox_attributes(&options, self.trust), content: Box::new(content) }
             },
             Token::Ampersand => Node::Ampersand,
-            Token::NewLine => Node::NewLine,
+            Token::NewLine => {
+                // `\\[4pt]` のような行間の指定. 長さとして読めない場合は `[` を通常の括弧として扱う
+                let gap = match self.peek_token {
+                    Token::Paren("[") => self.l.clone().read_raw_group('[', ']')
+                        .as_deref()
+                        .and_then(Length::parse),
+                    _ => None,
+                };
+                if gap.is_some() {
+                    self.parse_optional_raw_argument()?;
+                }
+                Node::NewLine(gap)
+            },
             token => Node::Undefined(format!("{:?}", token)),
         };
 
         match self.peek_token {
             Token::Operator('\'') => {
                 self.next_token();
-                Ok(Node::Superscript(Box::new(node), Box::new(Node::Operator('′'))))
+                Ok(Node::Superscript(Box::new(node), Box::new(Node::Operator('′', Variant::Normal))))
             },
             _ => Ok(node),
         }
@@ -362,30 +784,257 @@ impl<'a> Parser<'a> {
 
     fn parse_group(&mut self, end_token: &Token) -> Result<Node, LatexError> {
         self.next_token();
-        let mut nodes = Vec::new();
+        let nodes = self.parse_sequence(end_token, false)?;
+        Ok(into_row(nodes))
+    }
 
-        while {
-            if self.cur_token_is(&Token::EOF) { // 閉じ括弧がないまま入力が終了した場合
-                return Err(LatexError::UnexpectedToken{
-                    expected: end_token.clone(),
-                    got: self.cur_token.clone()
-                });
+    /// スタイルを `style` に切り替えて `parse_node()` を行う.
+    fn parse_node_in(&mut self, style: MathStyle) -> Result<Node, LatexError> {
+        let outer = std::mem::replace(&mut self.style, style);
+        let node = self.parse_node();
+        self.style = outer;
+        node
+    }
+
+    /// スタイルを `style` に切り替えて `parse_single_node()` を行う.
+    fn parse_single_node_in(&mut self, style: MathStyle) -> Result<Node, LatexError> {
+        let outer = std::mem::replace(&mut self.style, style);
+        let node = self.parse_single_node();
+        self.style = outer;
+        node
+    }
+
+    /// `\displaystyle` などの作用を終わらせ, 作用したノードをまとめる.
+    fn end_style(&mut self, nodes: &mut Vec<Node>, pending: &mut Option<(MathStyle, MathStyle, usize)>) {
+        if let Some((style, outer, start)) = pending.take() {
+            self.style = outer;
+            let content = into_row(nodes.split_off(start));
+            nodes.push(self.styled(style, content));
+        }
+    }
+
+    /// `content` をスタイル `style` の `<mstyle>` で包む.
+    /// 
+    /// `scriptlevel` は現在のスタイルから暗黙に決まる値と異なる場合のみ指定する.
+    fn styled(&self, style: MathStyle, content: Node) -> Node {
+        let scriptlevel = if style.scriptlevel() == self.style.scriptlevel() {
+            None
+        } else {
+            Some(style.scriptlevel())
+        };
+        Node::Style(Some(style.display()), scriptlevel, Box::new(content))
+    }
+
+    /// `target` の直後の `_`, `^` を上下の添字として読み込む.
+    fn parse_limits(&mut self, target: Node) -> Result<Node, LatexError> {
+        let mut under = None;
+        let mut over = None;
+        loop {
+            match self.peek_token {
+                Token::Underscore if under.is_none() => {
+                    self.next_token();
+                    self.next_token();
+                    under = Some(self.parse_single_node_in(self.style.script())?);
+                },
+                Token::Circumflex if over.is_none() => {
+                    self.next_token();
+                    self.next_token();
+                    over = Some(self.parse_single_node_in(self.style.script())?);
+                },
+                _ => break,
             }
+        }
+        let target = Box::new(target);
+        Ok(match (under, over) {
+            (Some(under), Some(over)) => Node::UnderOver{ target, under: Box::new(under), over: Box::new(over) },
+            (Some(under), None)       => Node::Under(target, Box::new(under)),
+            (None, Some(over))        => Node::Overset{ over: Box::new(over), target },
+            (None, None)              => *target,
+        })
+    }
 
-            !self.cur_token_is(end_token) 
-        } {
-            nodes.push(
-                self.parse_node()?
-            );
-            self.next_token();
+    /// 右下, 右上の添字 `_`, `^` があれば `target` に付ける.
+    fn parse_scripts(&mut self, target: Node) -> Result<Node, LatexError> {
+        let mut sub = None;
+        let mut sup = None;
+        loop {
+            match self.peek_token {
+                Token::Underscore if sub.is_none() => {
+                    self.next_token();
+                    self.next_token();
+                    sub = Some(self.parse_single_node_in(self.style.script())?);
+                },
+                Token::Circumflex if sup.is_none() => {
+                    self.next_token();
+                    self.next_token();
+                    sup = Some(self.parse_single_node_in(self.style.script())?);
+                },
+                _ => break,
+            }
+        }
+        let target = Box::new(target);
+        Ok(match (sub, sup) {
+            (Some(sub), Some(sup)) => Node::SubSup{ target, sub: Box::new(sub), sup: Box::new(sup) },
+            (Some(sub), None)      => Node::Subscript(target, Box::new(sub)),
+            (None, Some(sup))      => Node::Superscript(target, Box::new(sup)),
+            (None, None)           => *target,
+        })
+    }
+
+    /// 直後に `\limits`, `\nolimits` があれば読み込む.
+    fn parse_limits_token(&mut self) -> Option<bool> {
+        match self.peek_token {
+            Token::Limits(limits) => {
+                self.next_token();
+                Some(limits)
+            },
+            _ => None,
+        }
+    }
+
+    /// 直後の `{..}` の中身をそのまま文字列として読み込む.
+    /// 
+    /// 終了時は `cur_token` が `}` を指す.
+    fn parse_raw_argument(&mut self) -> Result<String, LatexError> {
+        if !self.peek_token_is(Token::LBrace) {
+            return Err(LatexError::UnexpectedToken{
+                expected: Token::LBrace, got: self.peek_token.clone(),
+            });
+        }
+        self.parse_raw_group(Token::RBrace)
+    }
+
+    /// 直後の `{..}` の中身を URL として読み込む. `%` はコメントとして扱わない.
+    fn parse_url_argument(&mut self) -> Result<String, LatexError> {
+        if !self.peek_token_is(Token::LBrace) {
+            return Err(LatexError::UnexpectedToken{
+                expected: Token::LBrace, got: self.peek_token.clone(),
+            });
         }
+        let url = self.l.read_verbatim_group()
+            .ok_or(LatexError::UnexpectedToken{ expected: Token::RBrace, got: Token::EOF })?;
+        self.cur_token = Token::RBrace;
+        self.peek_token = self.l.next_token();
+        Ok(url)
+    }
 
-        if nodes.len() == 1 {
-            let node = nodes.into_iter().nth(0).unwrap();
-            Ok(node)
+    /// 直後に `[..]` があればその中身をそのまま文字列として読み込む.
+    fn parse_optional_raw_argument(&mut self) -> Result<Option<String>, LatexError> {
+        if self.peek_token_is(Token::Paren("[")) {
+            Ok(Some(self.parse_raw_group(Token::Paren("]"))?))
         } else {
-            Ok(Node::Row(nodes))
+            Ok(None)
+        }
+    }
+
+    /// `[model]{spec}` の形式の色指定を読み込み, CSS の色に変換する.
+    fn parse_color_argument(&mut self) -> Result<String, LatexError> {
+        let model = self.parse_optional_raw_argument()?;
+        let spec = self.parse_raw_argument()?;
+        parse_color(model.as_deref(), &spec)
+            .ok_or(LatexError::UnknownColor(spec))
+    }
+
+    fn parse_raw_group(&mut self, close: Token) -> Result<String, LatexError> {
+        let (open_char, close_char) = match close {
+            Token::RBrace => ('{', '}'),
+            _             => ('[', ']'),
+        };
+        let text = self.l.read_raw_group(open_char, close_char)
+            .ok_or_else(|| LatexError::UnexpectedToken{
+                expected: close.clone(), got: Token::EOF,
+            })?;
+        self.cur_token = close;
+        self.peek_token = self.l.next_token();
+        Ok(text)
+    }
+
+    /// `\text{..}` などの引数 `text` をテキストモードで読み込む.
+    /// 
+    /// 空白は一つにまとめ, 両端の空白は `<mtext>` で消えないように non-breaking space にする.
+    /// `$..$` と `\(..\)` の中は数式として読み込む.
+    fn parse_text_mode(&mut self, text: &str, var: Variant) -> Result<Node, LatexError> {
+        let mut nodes = Vec::new();
+        self.parse_text_segments(text, var, &mut nodes)?;
+        if nodes.is_empty() {
+            nodes.push(Node::Text(String::new(), var));
         }
+        let nodes = nodes.into_iter()
+            .map(|node| match node {
+                Node::Text(text, var) => Node::Text(text_with_edges(&text), var),
+                node => node,
+            })
+            .collect();
+        Ok(into_row(nodes))
+    }
+
+    /// テキストモードで読み込んだ結果を `nodes` に追加する.
+    /// 
+    /// 同じフォントのテキストが続く場合は一つの `Node::Text` にまとめる.
+    fn parse_text_segments(&mut self, text: &str, var: Variant, nodes: &mut Vec<Node>) -> Result<(), LatexError> {
+        let mut l = Lexer::new(text);
+        loop {
+            match l.next_text_token() {
+                TextToken::EOF => break,
+                TextToken::Char(c) => push_text(nodes, c, var),
+                TextToken::Space => push_text(nodes, ' ', var),
+                // グループの括弧は出力しない
+                TextToken::LBrace | TextToken::RBrace => (),
+                TextToken::Command(command) => {
+                    if let Some(symbol) = text_symbol(&command) {
+                        symbol.chars().for_each(|c| push_text(nodes, c, var));
+                    } else if let Some(accent) = text_accent(&command) {
+                        // `\"o`, `\'{\i}` のようなアクセント
+                        let arg = l.read_text_argument().unwrap_or_default();
+                        let base = match arg.strip_prefix('\\').and_then(text_symbol) {
+                            Some(symbol) => symbol.to_owned(),
+                            None => arg,
+                        };
+                        let mut chars = base.chars();
+                        let accented = match chars.next() {
+                            Some(c) => compose(c, accent) + chars.as_str(),
+                            None => accent.to_string(),
+                        };
+                        accented.chars().for_each(|c| push_text(nodes, c, var));
+                    } else {
+                        // `\textbf{\textit{..}}` のように入れ子になったフォントの指定
+                        let inner = match Token::from_command(&command) {
+                            Token::Text(Some(font)) => Some(text_variant(var, font)),
+                            Token::Text(None)       => Some(var),
+                            Token::Emph             => Some(emph_variant(var)),
+                            _                       => None,
+                        };
+                        match inner {
+                            Some(inner) => {
+                                let arg = l.read_text_argument().unwrap_or_default();
+                                self.parse_text_segments(&arg, inner, nodes)?;
+                            },
+                            None => {
+                                push_text(nodes, '\\', var);
+                                command.chars().for_each(|c| push_text(nodes, c, var));
+                            },
+                        }
+                    }
+                },
+                TextToken::Math(math) => {
+                    nodes.extend(self.parse_inline_math(&math, var)?);
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// テキストの中の数式 `math` を読み込む.
+    /// 
+    /// 数式はインライン数式として扱うが, スクリプトスタイルなどはそのまま引き継ぐ.
+    /// 数式の中の `\text` は外側のテキストのフォント `text_font` を引き継ぐ.
+    fn parse_inline_math(&mut self, math: &str, text_font: Variant) -> Result<Vec<Node>, LatexError> {
+        let mut p = Parser::new(Lexer::with_number_format(math, self.l.number), DisplayStyle::Inline, self.trust);
+        if self.style != MathStyle::Display {
+            p.style = self.style;
+        }
+        p.text_font = text_font;
+        p.parse()
     }
 
     fn parse_text(&mut self) -> String {
@@ -404,14 +1053,288 @@ impl<'a> Parser<'a> {
     }
 }
 
-fn set_variant(node: Node, var: Variant) -> Node {
-    match node {
-        Node::Letter(x, _) => Node::Letter(x, var),
-        Node::Row(vec) => Node::Row(
-            vec.into_iter()
-                .map(|node| set_variant(node, var))
-                .collect()
-        ),
-        node => node,
+fn into_row(nodes: Vec<Node>) -> Node {
+    if nodes.len() == 1 {
+        nodes.into_iter().next().unwrap()
+    } else {
+        Node::Row(nodes)
     }
 }
+
+/// `\bmod`, `\pmod` などの `mod` (前後の空白は呼び出し側で置く)
+fn mod_operator() -> Node {
+    Node::Atom{
+        class: MathClass::Ord,
+        movablelimits: None,
+        content: Box::new(Node::Function("mod".to_owned(), Variant::Normal, None)),
+    }
+}
+
+/// 中置形式の分数があれば, `start` 以降のノードを分母として分数を作る.
+fn end_infix(nodes: &mut Vec<Node>, infix: &mut Option<(&'static str, &'static str, LineThickness, Vec<Node>)>, start: usize) {
+    if let Some((open, close, lt, numerator)) = infix.take() {
+        let denominator = nodes.split_off(start);
+        nodes.push(infix_frac(open, close, lt, numerator, denominator));
+    }
+}
+
+fn infix_frac(open: &'static str, close: &'static str, lt: LineThickness, numerator: Vec<Node>, denominator: Vec<Node>) -> Node {
+    let frac = Node::Frac(Box::new(into_row(numerator)), Box::new(into_row(denominator)), lt, FracAttr::Normal);
+    if open.is_empty() && close.is_empty() {
+        frac
+    } else {
+        Node::Fenced{ open, close, content: Box::new(frac) }
+    }
+}
+
+/// テキストのフォント `outer` の中で `\textbf` などのフォント `font` を指定したときのフォントを返す.
+/// 
+/// 書体 (`\textrm`, `\textsf`, `\texttt`), 太さ (`\textbf`), 形 (`\textit`) はそれぞれ独立に指定できる.
+fn text_variant(outer: Variant, font: Variant) -> Variant {
+    let (family, bold, italic) = text_font_of(outer);
+    match font {
+        Variant::Bold      => text_font_from(family, true, italic),
+        Variant::Italic    => text_font_from(family, bold, true),
+        Variant::SansSerif => text_font_from(Variant::SansSerif, bold, italic),
+        Variant::Monospace => text_font_from(Variant::Monospace, bold, italic),
+        _                  => text_font_from(Variant::Normal, bold, italic),
+    }
+}
+
+/// `\boldsymbol` の中のフォントを返す. 直立体は太字, イタリック体は太字イタリック体になる.
+fn bold_variant(var: Variant) -> Variant {
+    match var {
+        Variant::Normal          => Variant::Bold,
+        Variant::Italic          => Variant::BoldItalic,
+        Variant::Fraktur         => Variant::BoldFraktur,
+        Variant::Script          => Variant::BoldScript,
+        Variant::SansSerif       => Variant::BoldSansSerif,
+        Variant::SansSerifItalic => Variant::SansSerifBoldItalic,
+        var                      => var,
+    }
+}
+
+/// `\emph` は形をイタリックと直立で入れ替える.
+fn emph_variant(outer: Variant) -> Variant {
+    let (family, bold, italic) = text_font_of(outer);
+    text_font_from(family, bold, !italic)
+}
+
+/// フォントを書体, 太字かどうか, イタリックかどうかに分解する.
+fn text_font_of(var: Variant) -> (Variant, bool, bool) {
+    match var {
+        Variant::Bold                => (Variant::Normal, true, false),
+        Variant::Italic              => (Variant::Normal, false, true),
+        Variant::BoldItalic          => (Variant::Normal, true, true),
+        Variant::SansSerif           => (Variant::SansSerif, false, false),
+        Variant::BoldSansSerif       => (Variant::SansSerif, true, false),
+        Variant::SansSerifItalic     => (Variant::SansSerif, false, true),
+        Variant::SansSerifBoldItalic => (Variant::SansSerif, true, true),
+        Variant::Monospace           => (Variant::Monospace, false, false),
+        _                            => (Variant::Normal, false, false),
+    }
+}
+
+fn text_font_from(family: Variant, bold: bool, italic: bool) -> Variant {
+    match (family, bold, italic) {
+        (Variant::SansSerif, false, false) => Variant::SansSerif,
+        (Variant::SansSerif, true, false)  => Variant::BoldSansSerif,
+        (Variant::SansSerif, false, true)  => Variant::SansSerifItalic,
+        (Variant::SansSerif, true, true)   => Variant::SansSerifBoldItalic,
+        // MathML には太字やイタリックの等幅フォントがない
+        (Variant::Monospace, _, _)         => Variant::Monospace,
+        (_, false, false) => Variant::Normal,
+        (_, true, false)  => Variant::Bold,
+        (_, false, true)  => Variant::Italic,
+        (_, true, true)   => Variant::BoldItalic,
+    }
+}
+
+/// `nodes` の末尾に文字 `c` を追加する.
+/// 
+/// 末尾が同じフォントのテキストであればそこに追加し, 空白は連続させない.
+fn push_text(nodes: &mut Vec<Node>, c: char, var: Variant) {
+    if let Some(Node::Text(text, last)) = nodes.last_mut() {
+        if *last == var {
+            if !(c == ' ' && text.ends_with(' ')) {
+                text.push(c);
+            }
+            return;
+        }
+    }
+    nodes.push(Node::Text(c.to_string(), var));
+}
+
+/// 両端の空白を non-breaking space に置き換える.
+fn text_with_edges(text: &str) -> String {
+    let trimmed = text.trim_matches(' ');
+    let mut result = String::with_capacity(text.len());
+    if text.starts_with(' ') { result.push('\u{a0}'); }
+    result.push_str(trimmed);
+    if text.ends_with(' ') && !trimmed.is_empty() { result.push('\u{a0}'); }
+    result
+}
+
+/// `\not` で否定した関係演算子に対応する合成済みの文字を返す.
+fn negated(op: char) -> Option<char> {
+    let neg = match op {
+        '=' => '≠',
+        '<' => '≮',
+        '>' => '≯',
+        '≤' => '≰',
+        '≥' => '≱',
+        '∈' => '∉',
+        '∋' => '∌',
+        '∃' => '∄',
+        '⊂' => '⊄',
+        '⊃' => '⊅',
+        '⊆' => '⊈',
+        '⊇' => '⊉',
+        '≡' => '≢',
+        '∼' => '≁',
+        '≃' => '≄',
+        '≅' => '≇',
+        '≈' => '≉',
+        '≍' => '≭',
+        '≲' => '≴',
+        '≳' => '≵',
+        '≶' => '≸',
+        '≷' => '≹',
+        '∣' => '∤',
+        '∥' => '∦',
+        '≺' => '⊀',
+        '≻' => '⊁',
+        '≼' => '⋠',
+        '≽' => '⋡',
+        '⊑' => '⋢',
+        '⊒' => '⋣',
+        '⊲' => '⋪',
+        '⊳' => '⋫',
+        '⊴' => '⋬',
+        '⊵' => '⋭',
+        '⊢' => '⊬',
+        '⊨' => '⊭',
+        '⊩' => '⊮',
+        '⊫' => '⊯',
+        '←' => '↚',
+        '→' => '↛',
+        '↔' => '↮',
+        '⇐' => '⇍',
+        '⇒' => '⇏',
+        '⇔' => '⇎',
+        _ => { return None; },
+    };
+    Some(neg)
+}
+
+/// `\left` や `\big` などの直後に置ける括弧を返す.
+/// 
+/// `.` は空の括弧を表す.
+fn delimiter_of(token: &Token) -> Option<&'static str> {
+    let paren = match token {
+        Token::Paren(paren) => paren,
+        Token::Operator(op) => match op {
+            '.' => "",
+            '<' => "⟨",
+            '>' => "⟩",
+            '/' => "/",
+            '↑' => "↑",
+            '↓' => "↓",
+            '↕' => "↕",
+            '⇑' => "⇑",
+            '⇓' => "⇓",
+            '⇕' => "⇕",
+            _ => { return None; },
+        },
+        _ => { return None; },
+    };
+    Some(paren)
+}
+
+/// `\genfrac` などの引数として与えられた括弧を読み取る.
+fn delimiter(text: &str) -> &'static str {
+    delimiter_of(&Lexer::new(text.trim()).next_token()).unwrap_or("")
+}
+
+/// `\enclose` の `[mathcolor="red", mathbackground="yellow"]` のような属性を読み取る.
+/// 
+/// MathJax と同様に `mathcolor`, `mathbackground` のみを常に出力し, `style`, `class`, `id` と
+/// `data-*` は `trust` で許可されている場合だけ出力する. それ以外の属性は無視する.
+fn enclose_attributes(text: &str, trust: Trust) -> Vec<(String, String)> {
+    text.split(',')
+        .filter_map(|attr| attr.split_once('='))
+        .map(|(name, value)| (name.trim(), value.trim().trim_matches('"')))
+        .filter(|(name, _)| match name.to_ascii_lowercase().as_str() {
+            "mathcolor" | "mathbackground" => true,
+            "style" => trust.style,
+            "class" => trust.class,
+            "id"    => trust.id,
+            name    => trust.data && name.len() > "data-".len() && name.starts_with("data-")
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+        })
+        .map(|(name, value)| (name.to_owned(), value.to_owned()))
+        .collect()
+}
+
+/// `\bbox` の `[yellow,5px,border:1px solid red]` のようなオプションを `<mpadded>` の属性に変換する.
+/// 
+/// `border: ..` のような CSS の指定は `trust` で許可されている場合だけ `style` 属性にする.
+fn bbox_attributes(options: &str, trust: Trust) -> Vec<(&'static str, String)> {
+    let mut attrs = Vec::new();
+    let mut style = Vec::new();
+    for option in options.split(',').map(str::trim).filter(|option| !option.is_empty()) {
+        if let Some(padding) = Length::parse(option) {
+            attrs.extend(padding_attributes(padding));
+        } else if option.contains(':') {
+            style.push(option.trim_end_matches(';'));
+        } else if let Some(color) = parse_color(None, option) {
+            attrs.push(("mathbackground", color));
+        }
+    }
+    if trust.style && !style.is_empty() {
+        attrs.push(("style", style.join("; ")));
+    }
+    attrs
+}
+
+/// `\colorbox` などの内容の周りの余白
+const FBOXSEP: Length = Length::new(3., LengthUnit::Pt);
+
+/// 内容の周りに `padding` だけ余白をつける `<mpadded>` の属性
+fn padding_attributes(padding: Length) -> Vec<(&'static str, String)> {
+    vec![
+        ("width", format!("+{}", padding.scale(2.))),
+        ("height", format!("+{}", padding)),
+        ("depth", format!("+{}", padding)),
+        ("lspace", format!("{}", padding)),
+    ]
+}
+
+/// スクリプトを実行できる `javascript:`, `data:`, `vbscript:` の URL でないかどうか.
+/// 
+/// ブラウザと同様に, スキームの中の空白や制御文字は無視し, 大文字と小文字は区別しない.
+fn is_safe_url(url: &str) -> bool {
+    let url = url.chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let scheme = match url.split_once(':') {
+        Some((scheme, _)) if !scheme.contains(['/', '?', '#']) => scheme,
+        _ => { return true; },
+    };
+    !matches!(scheme, "javascript" | "data" | "vbscript")
+}
+
+/// `\htmlData` の `foo=a, bar=b` を `data-foo="a" data-bar="b"` に変換する.
+/// 
+/// キーに使えない文字を含む組は無視する.
+fn data_attributes(text: &str) -> Vec<(String, String)> {
+    text.split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .filter(|(key, _)| {
+            !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        })
+        .map(|(key, value)| (format!("data-{}", key), value.to_owned()))
+        .collect()
+}
@@ -0,0 +1,1982 @@
+//! Symbol
+//!
+//! unicode-math の記号コマンドの表.
+//! コマンド名, 文字, TeX のクラスの組をコマンド名の順に並べてあり, 二分探索で引く.
+//!
+//! 表は unicode-math パッケージ (https://github.com/latex3/unicode-math) の
+//! `unicode-math-table.tex` から `examples/symbol_table.rs` で生成する.
+//! 元のファイルは LaTeX Project Public License 1.3c で配布されている.
+//!
+//! ```sh
+//! cargo run --example symbol_table -- path/to/unicode-math-table.tex
+//! ```
+//!
+//! 現在の表を生成した unicode-math の版は記録されていない.
+//! 生成し直した場合は, 元にした版 (`\ProvidesExplPackage` の日付と版) をここに記すこと.
+//!
+
+/// unicode-math における記号のクラス
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Class {
+    /// 文字 (`\mathalpha`)
+    Alpha,
+    /// 通常の記号 (`\mathord`)
+    Ord,
+    /// 大型演算子 (`\mathop`)
+    Op,
+    /// 二項演算子 (`\mathbin`)
+    Bin,
+    /// 関係演算子 (`\mathrel`)
+    Rel,
+    /// 開き括弧 (`\mathopen`)
+    Open,
+    /// 閉じ括弧 (`\mathclose`)
+    Close,
+    /// 縦棒 (`\mathfence`)
+    Fence,
+    /// 句読点 (`\mathpunct`)
+    Punct,
+}
+
+/// コマンド名から記号とそのクラスを引く.
+///
+/// ```text
+/// lookup("nleq")    => Some(("≰", Class::Rel))
+/// lookup("mbfA")    => Some(("𝐀", Class::Alpha))
+/// lookup("unknown") => None
+/// ```
+pub(crate) fn lookup(name: &str) -> Option<(&'static str, Class)> {
+    SYMBOLS.binary_search_by(|(command, _, _)| (*command).cmp(name))
+        .ok()
+        .map(|i| (SYMBOLS[i].1, SYMBOLS[i].2))
+}
+
+use Class::*;
+
+/// unicode-math の記号の表 (コマンド名の順)
+static SYMBOLS: &[(&str, &str, Class)] = &[
+    ("AC",                    "∿", Ord),
+    ("APLcomment",            "⍝", Ord),
+    ("APLdownarrowbox",       "⍗", Ord),
+    ("APLinput",              "⍞", Ord),
+    ("APLinv",                "⌹", Ord),
+    ("APLleftarrowbox",       "⍇", Ord),
+    ("APLlog",                "⍟", Ord),
+    ("APLrightarrowbox",      "⍈", Ord),
+    ("APLuparrowbox",         "⍐", Ord),
+    ("Angstrom",              "Å", Alpha),
+    ("Aries",                 "♈", Ord),
+    ("Barv",                  "⫧", Rel),
+    ("BbbA",                  "𝔸", Alpha),
+    ("BbbB",                  "𝔹", Alpha),
+    ("BbbC",                  "ℂ", Alpha),
+    ("BbbD",                  "𝔻", Alpha),
+    ("BbbE",                  "𝔼", Alpha),
+    ("BbbF",                  "𝔽", Alpha),
+    ("BbbG",                  "𝔾", Alpha),
+    ("BbbH",                  "ℍ", Alpha),
+    ("BbbI",                  "𝕀", Alpha),
+    ("BbbJ",                  "𝕁", Alpha),
+    ("BbbK",                  "𝕂", Alpha),
+    ("BbbL",                  "𝕃", Alpha),
+    ("BbbM",                  "𝕄", Alpha),
+    ("BbbN",                  "ℕ", Alpha),
+    ("BbbO",                  "𝕆", Alpha),
+    ("BbbP",                  "ℙ", Alpha),
+    ("BbbQ",                  "ℚ", Alpha),
+    ("BbbR",                  "ℝ", Alpha),
+    ("BbbS",                  "𝕊", Alpha),
+    ("BbbT",                  "𝕋", Alpha),
+    ("BbbU",                  "𝕌", Alpha),
+    ("BbbV",                  "𝕍", Alpha),
+    ("BbbW",                  "𝕎", Alpha),
+    ("BbbX",                  "𝕏", Alpha),
+    ("BbbY",                  "𝕐", Alpha),
+    ("BbbZ",                  "ℤ", Alpha),
+    ("Bbba",                  "𝕒", Alpha),
+    ("Bbbb",                  "𝕓", Alpha),
+    ("Bbbc",                  "𝕔", Alpha),
+    ("Bbbd",                  "𝕕", Alpha),
+    ("Bbbe",                  "𝕖", Alpha),
+    ("Bbbeight",              "𝟠", Ord),
+    ("Bbbf",                  "𝕗", Alpha),
+    ("Bbbfive",               "𝟝", Ord),
+    ("Bbbfour",               "𝟜", Ord),
+    ("Bbbg",                  "𝕘", Alpha),
+    ("Bbbh",                  "𝕙", Alpha),
+    ("Bbbi",                  "𝕚", Alpha),
+    ("Bbbj",                  "𝕛", Alpha),
+    ("Bbbk",                  "𝕜", Alpha),
+    ("Bbbl",                  "𝕝", Alpha),
+    ("Bbbm",                  "𝕞", Alpha),
+    ("Bbbn",                  "𝕟", Alpha),
+    ("Bbbnine",               "𝟡", Ord),
+    ("Bbbo",                  "𝕠", Alpha),
+    ("Bbbone",                "𝟙", Ord),
+    ("Bbbp",                  "𝕡", Alpha),
+    ("Bbbq",                  "𝕢", Alpha),
+    ("Bbbr",                  "𝕣", Alpha),
+    ("Bbbs",                  "𝕤", Alpha),
+    ("Bbbseven",              "𝟟", Ord),
+    ("Bbbsix",                "𝟞", Ord),
+    ("Bbbt",                  "𝕥", Alpha),
+    ("Bbbthree",              "𝟛", Ord),
+    ("Bbbtwo",                "𝟚", Ord),
+    ("Bbbu",                  "𝕦", Alpha),
+    ("Bbbv",                  "𝕧", Alpha),
+    ("Bbbw",                  "𝕨", Alpha),
+    ("Bbbx",                  "𝕩", Alpha),
+    ("Bbby",                  "𝕪", Alpha),
+    ("Bbbz",                  "𝕫", Alpha),
+    ("Bbbzero",               "𝟘", Ord),
+    ("Bot",                   "⫫", Rel),
+    ("Box",                   "⬜", Ord),
+    ("Bumpeq",                "≎", Rel),
+    ("CIRCLE",                "●", Ord),
+    ("Cap",                   "⋒", Bin),
+    ("CheckedBox",            "☑", Ord),
+    ("Circle",                "○", Bin),
+    ("Colon",                 "∷", Rel),
+    ("Coloneqq",              "⩴", Rel),
+    ("Cup",                   "⋓", Bin),
+    ("DashV",                 "⫥", Rel),
+    ("Dashv",                 "⫤", Rel),
+    ("Ddownarrow",            "⤋", Rel),
+    ("Delta",                 "Δ", Alpha),
+    ("Diamond",               "◇", Ord),
+    ("Diamondblack",          "◆", Ord),
+    ("Diamonddot",            "⟐", Ord),
+    ("Doteq",                 "≑", Rel),
+    ("Downarrow",             "⇓", Rel),
+    ("Equiv",                 "≣", Rel),
+    ("Eulerconst",            "ℇ", Ord),
+    ("Finv",                  "Ⅎ", Ord),
+    ("Game",                  "⅁", Ord),
+    ("Gamma",                 "Γ", Alpha),
+    ("Gemini",                "♊", Ord),
+    ("Hermaphrodite",         "⚥", Ord),
+    ("Im",                    "ℑ", Alpha),
+    ("Join",                  "⨝", Op),
+    ("Jupiter",               "♃", Ord),
+    ("LEFTCIRCLE",            "◖", Ord),
+    ("LEFTcircle",            "◐", Ord),
+    ("LHD",                   "◀", Bin),
+    ("Lambda",                "Λ", Alpha),
+    ("Lbag",                  "⟅", Open),
+    ("Lbrbrak",               "⟬", Open),
+    ("Leftarrow",             "⇐", Rel),
+    ("Leftrightarrow",        "⇔", Rel),
+    ("Leo",                   "♌", Ord),
+    ("Libra",                 "♎", Ord),
+    ("Lleftarrow",            "⇚", Rel),
+    ("Longleftarrow",         "⟸", Rel),
+    ("Longleftrightarrow",    "⟺", Rel),
+    ("Longmapsfrom",          "⟽", Rel),
+    ("Longmapsto",            "⟾", Rel),
+    ("Longrightarrow",        "⟹", Rel),
+    ("Lparengtr",             "⦕", Open),
+    ("Lsh",                   "↰", Rel),
+    ("Lvzigzag",              "⧚", Open),
+    ("Mapsfrom",              "⤆", Rel),
+    ("Mapsto",                "⤇", Rel),
+    ("Mars",                  "♂", Ord),
+    ("Mercury",               "☿", Ord),
+    ("Nearrow",               "⇗", Rel),
+    ("Neptune",               "♆", Ord),
+    ("Nwarrow",               "⇖", Rel),
+    ("Omega",                 "Ω", Alpha),
+    ("P",                     "¶", Ord),
+    ("Perp",                  "⫫", Rel),
+    ("Phi",                   "Φ", Alpha),
+    ("Pi",                    "Π", Alpha),
+    ("Planckconst",           "ℎ", Ord),
+    ("Pluto",                 "♇", Ord),
+    ("Psi",                   "Ψ", Alpha),
+    ("RHD",                   "▶", Bin),
+    ("RIGHTCIRCLE",           "◗", Ord),
+    ("RIGHTcircle",           "◑", Ord),
+    ("Rbag",                  "⟆", Close),
+    ("Rbrbrak",               "⟭", Close),
+    ("Re",                    "ℜ", Alpha),
+    ("Rightarrow",            "⇒", Rel),
+    ("Rparenless",            "⦖", Close),
+    ("Rrightarrow",           "⇛", Rel),
+    ("Rsh",                   "↱", Rel),
+    ("Rvzigzag",              "⧛", Close),
+    ("S",                     "§", Ord),
+    ("Saturn",                "♄", Ord),
+    ("Scorpio",               "♏", Ord),
+    ("Searrow",               "⇘", Rel),
+    ("Sigma",                 "Σ", Alpha),
+    ("Square",                "☐", Ord),
+    ("Subset",                "⋐", Rel),
+    ("Sun",                   "☉", Ord),
+    ("Supset",                "⋑", Rel),
+    ("Swarrow",               "⇙", Rel),
+    ("Taurus",                "♉", Ord),
+    ("Theta",                 "Θ", Alpha),
+    ("Top",                   "⫪", Rel),
+    ("Uparrow",               "⇑", Rel),
+    ("Updownarrow",           "⇕", Rel),
+    ("Upsilon",               "Υ", Alpha),
+    ("Uranus",                "♅", Ord),
+    ("Uuparrow",              "⤊", Rel),
+    ("VDash",                 "⊫", Rel),
+    ("Vbar",                  "⫫", Rel),
+    ("Vdash",                 "⊩", Rel),
+    ("Venus",                 "♀", Ord),
+    ("Vert",                  "‖", Fence),
+    ("Vvdash",                "⊪", Rel),
+    ("Vvert",                 "⦀", Fence),
+    ("XBox",                  "☒", Ord),
+    ("Xi",                    "Ξ", Alpha),
+    ("Yup",                   "⅄", Ord),
+    ("Zbar",                  "Ƶ", Ord),
+    ("acidfree",              "♾", Ord),
+    ("acwopencirclearrow",    "↺", Rel),
+    ("adots",                 "⋰", Rel),
+    ("aleph",                 "ℵ", Alpha),
+    ("alpha",                 "α", Alpha),
+    ("amalg",                 "⨿", Bin),
+    ("angle",                 "∠", Ord),
+    ("apprge",                "≳", Rel),
+    ("apprle",                "≲", Rel),
+    ("approx",                "≈", Rel),
+    ("approxeq",              "≊", Rel),
+    ("aquarius",              "♒", Ord),
+    ("arceq",                 "≘", Rel),
+    ("aries",                 "♈", Ord),
+    ("arrowvert",             "⏐", Ord),
+    ("ast",                   "∗", Bin),
+    ("asymp",                 "≍", Rel),
+    ("awint",                 "⨑", Op),
+    ("backepsilon",           "∍", Rel),
+    ("backprime",             "‵", Ord),
+    ("backsim",               "∽", Rel),
+    ("backsimeq",             "⋍", Rel),
+    ("backslash",             "\\", Ord),
+    ("ballotcheck",           "☑", Ord),
+    ("ballotx",               "✗", Ord),
+    ("barV",                  "⫪", Rel),
+    ("barin",                 "⋶", Rel),
+    ("barleftharpoon",        "⥫", Rel),
+    ("barrightarrow",         "⇥", Rel),
+    ("barrightharpoon",       "⥭", Rel),
+    ("barwedge",              "⊼", Bin),
+    ("because",               "∵", Rel),
+    ("beta",                  "β", Alpha),
+    ("beth",                  "ℶ", Alpha),
+    ("between",               "≬", Rel),
+    ("bigblacktriangledown",  "▼", Ord),
+    ("bigblacktriangleup",    "▲", Ord),
+    ("bigbot",                "⟘", Op),
+    ("bigcap",                "⋂", Op),
+    ("bigcirc",               "◯", Bin),
+    ("bigcup",                "⋃", Op),
+    ("bigcupdot",             "⨃", Op),
+    ("biginterleave",         "⫼", Op),
+    ("bigodot",               "⨀", Op),
+    ("bigoplus",              "⨁", Op),
+    ("bigotimes",             "⨂", Op),
+    ("bigsqcap",              "⨅", Op),
+    ("bigsqcup",              "⨆", Op),
+    ("bigstar",               "★", Ord),
+    ("bigtimes",              "⨉", Op),
+    ("bigtop",                "⟙", Op),
+    ("bigtriangledown",       "▽", Bin),
+    ("bigtriangleleft",       "⨞", Op),
+    ("bigtriangleup",         "△", Bin),
+    ("biguplus",              "⨄", Op),
+    ("bigvee",                "⋁", Op),
+    ("bigwedge",              "⋀", Op),
+    ("bigwhitestar",          "☆", Ord),
+    ("bindnasrepma",          "⅋", Bin),
+    ("blacklozenge",          "⧫", Bin),
+    ("blacksmiley",           "☻", Ord),
+    ("blacksquare",           "◼", Ord),
+    ("blacktriangle",         "▴", Ord),
+    ("blacktriangledown",     "▾", Ord),
+    ("blacktriangleleft",     "◂", Rel),
+    ("blacktriangleright",    "▸", Rel),
+    ("blacktriangleup",       "▴", Ord),
+    ("bot",                   "⊥", Ord),
+    ("bowtie",                "⋈", Rel),
+    ("boxast",                "⧆", Bin),
+    ("boxbar",                "◫", Bin),
+    ("boxbox",                "⧈", Bin),
+    ("boxbslash",             "⧅", Bin),
+    ("boxcircle",             "⧇", Bin),
+    ("boxdot",                "⊡", Bin),
+    ("boxminus",              "⊟", Bin),
+    ("boxplus",               "⊞", Bin),
+    ("boxslash",              "⧄", Bin),
+    ("boxtimes",              "⊠", Bin),
+    ("boy",                   "♂", Ord),
+    ("bracevert",             "⎪", Ord),
+    ("bullet",                "•", Bin),
+    ("bumpeq",                "≏", Rel),
+    ("cancer",                "♋", Ord),
+    ("cap",                   "∩", Bin),
+    ("capricornus",           "♑", Ord),
+    ("carriagereturn",        "↵", Ord),
+    ("cdot",                  "⋅", Bin),
+    ("cdotp",                 "·", Punct),
+    ("cdots",                 "⋯", Ord),
+    ("cent",                  "¢", Ord),
+    ("checkmark",             "✓", Ord),
+    ("chi",                   "χ", Alpha),
+    ("circ",                  "∘", Bin),
+    ("circeq",                "≗", Rel),
+    ("circlearrowleft",       "↺", Rel),
+    ("circlearrowright",      "↻", Rel),
+    ("circledR",              "Ⓡ", Ord),
+    ("circledS",              "Ⓢ", Ord),
+    ("circledast",            "⊛", Bin),
+    ("circledbslash",         "⦸", Bin),
+    ("circledcirc",           "⊚", Bin),
+    ("circleddash",           "⊝", Bin),
+    ("circledgtr",            "⧁", Bin),
+    ("circledless",           "⧀", Bin),
+    ("circledstar",           "✪", Ord),
+    ("circleonrightarrow",    "⇴", Rel),
+    ("cirfnint",              "⨐", Op),
+    ("clubsuit",              "♣", Ord),
+    ("colon",                 ":", Punct),
+    ("coloneq",               "≔", Rel),
+    ("coloneqq",              "≔", Rel),
+    ("complement",            "∁", Ord),
+    ("cong",                  "≅", Rel),
+    ("conjquant",             "⨇", Op),
+    ("coprod",                "∐", Op),
+    ("corresponds",           "≙", Rel),
+    ("cup",                   "∪", Bin),
+    ("curlyeqprec",           "⋞", Rel),
+    ("curlyeqsucc",           "⋟", Rel),
+    ("curlyvee",              "⋎", Bin),
+    ("curlywedge",            "⋏", Bin),
+    ("curvearrowleft",        "↶", Rel),
+    ("curvearrowright",       "↷", Rel),
+    ("cwopencirclearrow",     "↻", Rel),
+    ("dagger",                "†", Bin),
+    ("daleth",                "ℸ", Alpha),
+    ("danger",                "☡", Ord),
+    ("dashV",                 "⫣", Rel),
+    ("dasharrow",             "⇢", Rel),
+    ("dashcolon",             "∹", Rel),
+    ("dashleftarrow",         "⇠", Rel),
+    ("dashrightarrow",        "⇢", Rel),
+    ("dashv",                 "⊣", Rel),
+    ("ddagger",               "‡", Bin),
+    ("ddots",                 "⋱", Punct),
+    ("delta",                 "δ", Alpha),
+    ("diagdown",              "⟍", Ord),
+    ("diagup",                "⟋", Ord),
+    ("diameter",              "⌀", Ord),
+    ("diamond",               "⋄", Bin),
+    ("diamondsuit",           "♢", Ord),
+    ("digamma",               "ϝ", Alpha),
+    ("dingasterisk",          "✽", Ord),
+    ("disjquant",             "⨈", Op),
+    ("div",                   "÷", Bin),
+    ("divideontimes",         "⋇", Bin),
+    ("dlsh",                  "↲", Rel),
+    ("doteq",                 "≐", Rel),
+    ("doteqdot",              "≑", Rel),
+    ("dotminus",              "∸", Bin),
+    ("dotplus",               "∔", Bin),
+    ("dotsminusdots",         "∺", Rel),
+    ("doublebarwedge",        "⩞", Bin),
+    ("downarrow",             "↓", Rel),
+    ("downdasharrow",         "⇣", Rel),
+    ("downdownarrows",        "⇊", Rel),
+    ("downdownharpoons",      "⥥", Rel),
+    ("downharpoonleft",       "⇃", Rel),
+    ("downharpoonright",      "⇂", Rel),
+    ("downuparrows",          "⇵", Rel),
+    ("downupharpoons",        "⥯", Rel),
+    ("downwhitearrow",        "⇩", Rel),
+    ("drsh",                  "↳", Rel),
+    ("earth",                 "♁", Ord),
+    ("eighthnote",            "♪", Ord),
+    ("ell",                   "ℓ", Alpha),
+    ("emptyset",              "∅", Ord),
+    ("emptysetoarr",          "⦳", Ord),
+    ("emptysetoarrl",         "⦴", Ord),
+    ("emptysetobar",          "⦱", Ord),
+    ("emptysetocirc",         "⦲", Ord),
+    ("epsilon",               "ϵ", Alpha),
+    ("eqcirc",                "≖", Rel),
+    ("eqcolon",               "≕", Rel),
+    ("eqdef",                 "≝", Rel),
+    ("eqqcolon",              "≕", Rel),
+    ("eqsim",                 "≂", Rel),
+    ("eqslantgtr",            "⪖", Rel),
+    ("eqslantless",           "⪕", Rel),
+    ("equiv",                 "≡", Rel),
+    ("eta",                   "η", Alpha),
+    ("eth",                   "ð", Alpha),
+    ("exists",                "∃", Ord),
+    ("fallingdotseq",         "≒", Rel),
+    ("fatsemi",               "⨟", Op),
+    ("female",                "♀", Ord),
+    ("fint",                  "⨏", Op),
+    ("flat",                  "♭", Ord),
+    ("forall",                "∀", Ord),
+    ("forksnot",              "⫝", Rel),
+    ("fourth",                "⁗", Ord),
+    ("frown",                 "⌢", Rel),
+    ("frownie",               "☹", Ord),
+    ("fullouterjoin",         "⟗", Op),
+    ("gamma",                 "γ", Alpha),
+    ("ge",                    "≥", Rel),
+    ("gemini",                "♊", Ord),
+    ("geq",                   "≥", Rel),
+    ("geqq",                  "≧", Rel),
+    ("geqqslant",             "⫺", Rel),
+    ("geqslant",              "⩾", Rel),
+    ("gesdot",                "⪀", Rel),
+    ("gets",                  "←", Rel),
+    ("gg",                    "≫", Rel),
+    ("ggcurly",               "⪼", Rel),
+    ("ggg",                   "⋙", Rel),
+    ("gggnest",               "⫸", Rel),
+    ("gggtr",                 "⋙", Rel),
+    ("gimel",                 "ℷ", Alpha),
+    ("girl",                  "♀", Ord),
+    ("gnapprox",              "⪊", Rel),
+    ("gneq",                  "⪈", Rel),
+    ("gneqq",                 "≩", Rel),
+    ("gnsim",                 "⋧", Rel),
+    ("gtcir",                 "⩺", Rel),
+    ("gtrapprox",             "⪆", Rel),
+    ("gtrdot",                "⋗", Bin),
+    ("gtreqless",             "⋛", Rel),
+    ("gtreqqless",            "⪌", Rel),
+    ("gtrless",               "≷", Rel),
+    ("gtrsim",                "≳", Rel),
+    ("hash",                  "⋕", Rel),
+    ("hbar",                  "ℏ", Ord),
+    ("heartsuit",             "♡", Ord),
+    ("heavycheckmark",        "✔", Ord),
+    ("hermitmatrix",          "⊹", Ord),
+    ("hookleftarrow",         "↩", Rel),
+    ("hookrightarrow",        "↪", Rel),
+    ("hslash",                "ℏ", Alpha),
+    ("iddots",                "⋰", Rel),
+    ("iiiint",                "⨌", Op),
+    ("iiint",                 "∭", Op),
+    ("iint",                  "∬", Op),
+    ("imageof",               "⊷", Rel),
+    ("imath",                 "ı", Alpha),
+    ("impliedby",             "⟸", Rel),
+    ("implies",               "⟹", Rel),
+    ("in",                    "∈", Rel),
+    ("increment",             "∆", Ord),
+    ("infty",                 "∞", Ord),
+    ("int",                   "∫", Op),
+    ("intBar",                "⨎", Op),
+    ("intbar",                "⨍", Op),
+    ("intcap",                "⨙", Op),
+    ("intclockwise",          "∱", Op),
+    ("intcup",                "⨚", Op),
+    ("intercal",              "⊺", Bin),
+    ("interleave",            "⫴", Bin),
+    ("intlarhk",              "⨗", Op),
+    ("intop",                 "∫", Op),
+    ("intx",                  "⨘", Op),
+    ("invamp",                "⅋", Bin),
+    ("invdiameter",           "⍉", Ord),
+    ("invneg",                "⌐", Ord),
+    ("iota",                  "ι", Alpha),
+    ("jmath",                 "ȷ", Alpha),
+    ("jupiter",               "♃", Ord),
+    ("kappa",                 "κ", Alpha),
+    ("kernelcontraction",     "∻", Rel),
+    ("lAngle",                "⟪", Open),
+    ("lBrace",                "⦃", Open),
+    ("lBrack",                "⟦", Open),
+    ("lParen",                "⦅", Open),
+    ("lambda",                "λ", Alpha),
+    ("land",                  "∧", Bin),
+    ("langle",                "⟨", Open),
+    ("lbag",                  "⟅", Open),
+    ("lblkbrbrak",            "⦗", Open),
+    ("lbrace",                "{", Open),
+    ("lbrack",                "[", Open),
+    ("lbrackubar",            "⦋", Open),
+    ("lbrbrak",               "❲", Open),
+    ("lceil",                 "⌈", Open),
+    ("lcurvyangle",           "⧼", Open),
+    ("ldots",                 "…", Ord),
+    ("le",                    "≤", Rel),
+    ("leadsto",               "⤳", Rel),
+    ("leftarrow",             "←", Rel),
+    ("leftarrowbar",          "⇤", Rel),
+    ("leftarrowtail",         "↢", Rel),
+    ("leftarrowtriangle",     "⇽", Rel),
+    ("leftbarharpoon",        "⥪", Rel),
+    ("leftdasharrow",         "⇠", Rel),
+    ("leftharpoondown",       "↽", Rel),
+    ("leftharpoonup",         "↼", Rel),
+    ("leftleftarrows",        "⇇", Rel),
+    ("leftleftharpoons",      "⥢", Rel),
+    ("leftmoon",              "☾", Ord),
+    ("leftouterjoin",         "⟕", Op),
+    ("leftrightarrow",        "↔", Rel),
+    ("leftrightarrows",       "⇆", Rel),
+    ("leftrightarrowtriangle", "⇿", Rel),
+    ("leftrightharpoon",      "⥊", Rel),
+    ("leftrightharpoons",     "⇋", Rel),
+    ("leftrightsquigarrow",   "↭", Rel),
+    ("leftslice",             "⪦", Rel),
+    ("leftsquigarrow",        "⇜", Rel),
+    ("leftthreetimes",        "⋋", Bin),
+    ("leftturn",              "↺", Rel),
+    ("leftwhitearrow",        "⇦", Rel),
+    ("leo",                   "♌", Ord),
+    ("leq",                   "≤", Rel),
+    ("leqq",                  "≦", Rel),
+    ("leqqslant",             "⫹", Rel),
+    ("leqslant",              "⩽", Rel),
+    ("lesdot",                "⩿", Rel),
+    ("lessapprox",            "⪅", Rel),
+    ("lessdot",               "⋖", Bin),
+    ("lesseqgtr",             "⋚", Rel),
+    ("lesseqqgtr",            "⪋", Rel),
+    ("lessgtr",               "≶", Rel),
+    ("lesssim",               "≲", Rel),
+    ("lfloor",                "⌊", Open),
+    ("lgroup",                "⟮", Open),
+    ("lhd",                   "⊲", Rel),
+    ("libra",                 "♎", Ord),
+    ("lightning",             "↯", Rel),
+    ("linefeed",              "↴", Ord),
+    ("ll",                    "≪", Rel),
+    ("llbracket",             "⟦", Open),
+    ("llcorner",              "⌞", Open),
+    ("llcurly",               "⪻", Rel),
+    ("lll",                   "⋘", Rel),
+    ("llless",                "⋘", Rel),
+    ("lllnest",               "⫷", Rel),
+    ("llparenthesis",         "⦇", Open),
+    ("lmoustache",            "⎰", Ord),
+    ("lnapprox",              "⪉", Rel),
+    ("lneq",                  "⪇", Rel),
+    ("lneqq",                 "≨", Rel),
+    ("lnot",                  "¬", Ord),
+    ("lnsim",                 "⋦", Rel),
+    ("longleftarrow",         "⟵", Rel),
+    ("longleftrightarrow",    "⟷", Rel),
+    ("longmapsfrom",          "⟻", Rel),
+    ("longmapsto",            "⟼", Rel),
+    ("longrightarrow",        "⟶", Rel),
+    ("looparrowleft",         "↫", Rel),
+    ("looparrowright",        "↬", Rel),
+    ("lor",                   "∨", Bin),
+    ("lowint",                "⨜", Op),
+    ("lozenge",               "◊", Ord),
+    ("lparenless",            "⦓", Open),
+    ("lrcorner",              "⌟", Close),
+    ("lrtimes",               "⋈", Rel),
+    ("ltcir",                 "⩹", Rel),
+    ("ltimes",                "⋉", Bin),
+    ("lvzigzag",              "⧘", Open),
+    ("male",                  "♂", Ord),
+    ("maltese",               "✠", Ord),
+    ("mapsdown",              "↧", Rel),
+    ("mapsfrom",              "↤", Rel),
+    ("mapsto",                "↦", Rel),
+    ("mapsup",                "↥", Rel),
+    ("mathcent",              "¢", Ord),
+    ("mathcolon",             ":", Punct),
+    ("mathcomma",             ",", Punct),
+    ("mathdollar",            "$", Ord),
+    ("mathparagraph",         "¶", Ord),
+    ("mathperiod",            ".", Ord),
+    ("mathsection",           "§", Ord),
+    ("mathsemicolon",         ";", Punct),
+    ("mathsterling",          "£", Ord),
+    ("mbfA",                  "𝐀", Alpha),
+    ("mbfAlpha",              "𝚨", Alpha),
+    ("mbfB",                  "𝐁", Alpha),
+    ("mbfBeta",               "𝚩", Alpha),
+    ("mbfC",                  "𝐂", Alpha),
+    ("mbfChi",                "𝚾", Alpha),
+    ("mbfD",                  "𝐃", Alpha),
+    ("mbfDelta",              "𝚫", Alpha),
+    ("mbfE",                  "𝐄", Alpha),
+    ("mbfEpsilon",            "𝚬", Alpha),
+    ("mbfEta",                "𝚮", Alpha),
+    ("mbfF",                  "𝐅", Alpha),
+    ("mbfG",                  "𝐆", Alpha),
+    ("mbfGamma",              "𝚪", Alpha),
+    ("mbfH",                  "𝐇", Alpha),
+    ("mbfI",                  "𝐈", Alpha),
+    ("mbfIota",               "𝚰", Alpha),
+    ("mbfJ",                  "𝐉", Alpha),
+    ("mbfK",                  "𝐊", Alpha),
+    ("mbfKappa",              "𝚱", Alpha),
+    ("mbfL",                  "𝐋", Alpha),
+    ("mbfLambda",             "𝚲", Alpha),
+    ("mbfM",                  "𝐌", Alpha),
+    ("mbfMu",                 "𝚳", Alpha),
+    ("mbfN",                  "𝐍", Alpha),
+    ("mbfNu",                 "𝚴", Alpha),
+    ("mbfO",                  "𝐎", Alpha),
+    ("mbfOmega",              "𝛀", Alpha),
+    ("mbfOmicron",            "𝚶", Alpha),
+    ("mbfP",                  "𝐏", Alpha),
+    ("mbfPhi",                "𝚽", Alpha),
+    ("mbfPi",                 "𝚷", Alpha),
+    ("mbfPsi",                "𝚿", Alpha),
+    ("mbfQ",                  "𝐐", Alpha),
+    ("mbfR",                  "𝐑", Alpha),
+    ("mbfRho",                "𝚸", Alpha),
+    ("mbfS",                  "𝐒", Alpha),
+    ("mbfSigma",              "𝚺", Alpha),
+    ("mbfT",                  "𝐓", Alpha),
+    ("mbfTau",                "𝚻", Alpha),
+    ("mbfTheta",              "𝚯", Alpha),
+    ("mbfU",                  "𝐔", Alpha),
+    ("mbfUpsilon",            "𝚼", Alpha),
+    ("mbfV",                  "𝐕", Alpha),
+    ("mbfW",                  "𝐖", Alpha),
+    ("mbfX",                  "𝐗", Alpha),
+    ("mbfXi",                 "𝚵", Alpha),
+    ("mbfY",                  "𝐘", Alpha),
+    ("mbfZ",                  "𝐙", Alpha),
+    ("mbfZeta",               "𝚭", Alpha),
+    ("mbfa",                  "𝐚", Alpha),
+    ("mbfalpha",              "𝛂", Alpha),
+    ("mbfb",                  "𝐛", Alpha),
+    ("mbfbeta",               "𝛃", Alpha),
+    ("mbfc",                  "𝐜", Alpha),
+    ("mbfchi",                "𝛘", Alpha),
+    ("mbfd",                  "𝐝", Alpha),
+    ("mbfdelta",              "𝛅", Alpha),
+    ("mbfe",                  "𝐞", Alpha),
+    ("mbfeight",              "𝟖", Ord),
+    ("mbfepsilon",            "𝛜", Alpha),
+    ("mbfeta",                "𝛈", Alpha),
+    ("mbff",                  "𝐟", Alpha),
+    ("mbffive",               "𝟓", Ord),
+    ("mbffour",               "𝟒", Ord),
+    ("mbffrakA",              "𝕬", Alpha),
+    ("mbffrakB",              "𝕭", Alpha),
+    ("mbffrakC",              "𝕮", Alpha),
+    ("mbffrakD",              "𝕯", Alpha),
+    ("mbffrakE",              "𝕰", Alpha),
+    ("mbffrakF",              "𝕱", Alpha),
+    ("mbffrakG",              "𝕲", Alpha),
+    ("mbffrakH",              "𝕳", Alpha),
+    ("mbffrakI",              "𝕴", Alpha),
+    ("mbffrakJ",              "𝕵", Alpha),
+    ("mbffrakK",              "𝕶", Alpha),
+    ("mbffrakL",              "𝕷", Alpha),
+    ("mbffrakM",              "𝕸", Alpha),
+    ("mbffrakN",              "𝕹", Alpha),
+    ("mbffrakO",              "𝕺", Alpha),
+    ("mbffrakP",              "𝕻", Alpha),
+    ("mbffrakQ",              "𝕼", Alpha),
+    ("mbffrakR",              "𝕽", Alpha),
+    ("mbffrakS",              "𝕾", Alpha),
+    ("mbffrakT",              "𝕿", Alpha),
+    ("mbffrakU",              "𝖀", Alpha),
+    ("mbffrakV",              "𝖁", Alpha),
+    ("mbffrakW",              "𝖂", Alpha),
+    ("mbffrakX",              "𝖃", Alpha),
+    ("mbffrakY",              "𝖄", Alpha),
+    ("mbffrakZ",              "𝖅", Alpha),
+    ("mbffraka",              "𝖆", Alpha),
+    ("mbffrakb",              "𝖇", Alpha),
+    ("mbffrakc",              "𝖈", Alpha),
+    ("mbffrakd",              "𝖉", Alpha),
+    ("mbffrake",              "𝖊", Alpha),
+    ("mbffrakf",              "𝖋", Alpha),
+    ("mbffrakg",              "𝖌", Alpha),
+    ("mbffrakh",              "𝖍", Alpha),
+    ("mbffraki",              "𝖎", Alpha),
+    ("mbffrakj",              "𝖏", Alpha),
+    ("mbffrakk",              "𝖐", Alpha),
+    ("mbffrakl",              "𝖑", Alpha),
+    ("mbffrakm",              "𝖒", Alpha),
+    ("mbffrakn",              "𝖓", Alpha),
+    ("mbffrako",              "𝖔", Alpha),
+    ("mbffrakp",              "𝖕", Alpha),
+    ("mbffrakq",              "𝖖", Alpha),
+    ("mbffrakr",              "𝖗", Alpha),
+    ("mbffraks",              "𝖘", Alpha),
+    ("mbffrakt",              "𝖙", Alpha),
+    ("mbffraku",              "𝖚", Alpha),
+    ("mbffrakv",              "𝖛", Alpha),
+    ("mbffrakw",              "𝖜", Alpha),
+    ("mbffrakx",              "𝖝", Alpha),
+    ("mbffraky",              "𝖞", Alpha),
+    ("mbffrakz",              "𝖟", Alpha),
+    ("mbfg",                  "𝐠", Alpha),
+    ("mbfgamma",              "𝛄", Alpha),
+    ("mbfh",                  "𝐡", Alpha),
+    ("mbfi",                  "𝐢", Alpha),
+    ("mbfiota",               "𝛊", Alpha),
+    ("mbfitA",                "𝑨", Alpha),
+    ("mbfitAlpha",            "𝜜", Alpha),
+    ("mbfitB",                "𝑩", Alpha),
+    ("mbfitBeta",             "𝜝", Alpha),
+    ("mbfitC",                "𝑪", Alpha),
+    ("mbfitChi",              "𝜲", Alpha),
+    ("mbfitD",                "𝑫", Alpha),
+    ("mbfitDelta",            "𝜟", Alpha),
+    ("mbfitE",                "𝑬", Alpha),
+    ("mbfitEpsilon",          "𝜠", Alpha),
+    ("mbfitEta",              "𝜢", Alpha),
+    ("mbfitF",                "𝑭", Alpha),
+    ("mbfitG",                "𝑮", Alpha),
+    ("mbfitGamma",            "𝜞", Alpha),
+    ("mbfitH",                "𝑯", Alpha),
+    ("mbfitI",                "𝑰", Alpha),
+    ("mbfitIota",             "𝜤", Alpha),
+    ("mbfitJ",                "𝑱", Alpha),
+    ("mbfitK",                "𝑲", Alpha),
+    ("mbfitKappa",            "𝜥", Alpha),
+    ("mbfitL",                "𝑳", Alpha),
+    ("mbfitLambda",           "𝜦", Alpha),
+    ("mbfitM",                "𝑴", Alpha),
+    ("mbfitMu",               "𝜧", Alpha),
+    ("mbfitN",                "𝑵", Alpha),
+    ("mbfitNu",               "𝜨", Alpha),
+    ("mbfitO",                "𝑶", Alpha),
+    ("mbfitOmega",            "𝜴", Alpha),
+    ("mbfitOmicron",          "𝜪", Alpha),
+    ("mbfitP",                "𝑷", Alpha),
+    ("mbfitPhi",              "𝜱", Alpha),
+    ("mbfitPi",               "𝜫", Alpha),
+    ("mbfitPsi",              "𝜳", Alpha),
+    ("mbfitQ",                "𝑸", Alpha),
+    ("mbfitR",                "𝑹", Alpha),
+    ("mbfitRho",              "𝜬", Alpha),
+    ("mbfitS",                "𝑺", Alpha),
+    ("mbfitSigma",            "𝜮", Alpha),
+    ("mbfitT",                "𝑻", Alpha),
+    ("mbfitTau",              "𝜯", Alpha),
+    ("mbfitTheta",            "𝜣", Alpha),
+    ("mbfitU",                "𝑼", Alpha),
+    ("mbfitUpsilon",          "𝜰", Alpha),
+    ("mbfitV",                "𝑽", Alpha),
+    ("mbfitW",                "𝑾", Alpha),
+    ("mbfitX",                "𝑿", Alpha),
+    ("mbfitXi",               "𝜩", Alpha),
+    ("mbfitY",                "𝒀", Alpha),
+    ("mbfitZ",                "𝒁", Alpha),
+    ("mbfitZeta",             "𝜡", Alpha),
+    ("mbfita",                "𝒂", Alpha),
+    ("mbfitalpha",            "𝜶", Alpha),
+    ("mbfitb",                "𝒃", Alpha),
+    ("mbfitbeta",             "𝜷", Alpha),
+    ("mbfitc",                "𝒄", Alpha),
+    ("mbfitchi",              "𝝌", Alpha),
+    ("mbfitd",                "𝒅", Alpha),
+    ("mbfitdelta",            "𝜹", Alpha),
+    ("mbfite",                "𝒆", Alpha),
+    ("mbfitepsilon",          "𝝐", Alpha),
+    ("mbfiteta",              "𝜼", Alpha),
+    ("mbfitf",                "𝒇", Alpha),
+    ("mbfitg",                "𝒈", Alpha),
+    ("mbfitgamma",            "𝜸", Alpha),
+    ("mbfith",                "𝒉", Alpha),
+    ("mbfiti",                "𝒊", Alpha),
+    ("mbfitiota",             "𝜾", Alpha),
+    ("mbfitj",                "𝒋", Alpha),
+    ("mbfitk",                "𝒌", Alpha),
+    ("mbfitkappa",            "𝜿", Alpha),
+    ("mbfitl",                "𝒍", Alpha),
+    ("mbfitlambda",           "𝝀", Alpha),
+    ("mbfitm",                "𝒎", Alpha),
+    ("mbfitmu",               "𝝁", Alpha),
+    ("mbfitn",                "𝒏", Alpha),
+    ("mbfitnabla",            "𝜵", Alpha),
+    ("mbfitnu",               "𝝂", Alpha),
+    ("mbfito",                "𝒐", Alpha),
+    ("mbfitomega",            "𝝎", Alpha),
+    ("mbfitomicron",          "𝝄", Alpha),
+    ("mbfitp",                "𝒑", Alpha),
+    ("mbfitpartial",          "𝝏", Alpha),
+    ("mbfitphi",              "𝝓", Alpha),
+    ("mbfitpi",               "𝝅", Alpha),
+    ("mbfitpsi",              "𝝍", Alpha),
+    ("mbfitq",                "𝒒", Alpha),
+    ("mbfitr",                "𝒓", Alpha),
+    ("mbfitrho",              "𝝆", Alpha),
+    ("mbfits",                "𝒔", Alpha),
+    ("mbfitsansA",            "𝘼", Alpha),
+    ("mbfitsansAlpha",        "𝞐", Alpha),
+    ("mbfitsansB",            "𝘽", Alpha),
+    ("mbfitsansBeta",         "𝞑", Alpha),
+    ("mbfitsansC",            "𝘾", Alpha),
+    ("mbfitsansChi",          "𝞦", Alpha),
+    ("mbfitsansD",            "𝘿", Alpha),
+    ("mbfitsansDelta",        "𝞓", Alpha),
+    ("mbfitsansE",            "𝙀", Alpha),
+    ("mbfitsansEpsilon",      "𝞔", Alpha),
+    ("mbfitsansEta",          "𝞖", Alpha),
+    ("mbfitsansF",            "𝙁", Alpha),
+    ("mbfitsansG",            "𝙂", Alpha),
+    ("mbfitsansGamma",        "𝞒", Alpha),
+    ("mbfitsansH",            "𝙃", Alpha),
+    ("mbfitsansI",            "𝙄", Alpha),
+    ("mbfitsansIota",         "𝞘", Alpha),
+    ("mbfitsansJ",            "𝙅", Alpha),
+    ("mbfitsansK",            "𝙆", Alpha),
+    ("mbfitsansKappa",        "𝞙", Alpha),
+    ("mbfitsansL",            "𝙇", Alpha),
+    ("mbfitsansLambda",       "𝞚", Alpha),
+    ("mbfitsansM",            "𝙈", Alpha),
+    ("mbfitsansMu",           "𝞛", Alpha),
+    ("mbfitsansN",            "𝙉", Alpha),
+    ("mbfitsansNu",           "𝞜", Alpha),
+    ("mbfitsansO",            "𝙊", Alpha),
+    ("mbfitsansOmega",        "𝞨", Alpha),
+    ("mbfitsansOmicron",      "𝞞", Alpha),
+    ("mbfitsansP",            "𝙋", Alpha),
+    ("mbfitsansPhi",          "𝞥", Alpha),
+    ("mbfitsansPi",           "𝞟", Alpha),
+    ("mbfitsansPsi",          "𝞧", Alpha),
+    ("mbfitsansQ",            "𝙌", Alpha),
+    ("mbfitsansR",            "𝙍", Alpha),
+    ("mbfitsansRho",          "𝞠", Alpha),
+    ("mbfitsansS",            "𝙎", Alpha),
+    ("mbfitsansSigma",        "𝞢", Alpha),
+    ("mbfitsansT",            "𝙏", Alpha),
+    ("mbfitsansTau",          "𝞣", Alpha),
+    ("mbfitsansTheta",        "𝞗", Alpha),
+    ("mbfitsansU",            "𝙐", Alpha),
+    ("mbfitsansUpsilon",      "𝞤", Alpha),
+    ("mbfitsansV",            "𝙑", Alpha),
+    ("mbfitsansW",            "𝙒", Alpha),
+    ("mbfitsansX",            "𝙓", Alpha),
+    ("mbfitsansXi",           "𝞝", Alpha),
+    ("mbfitsansY",            "𝙔", Alpha),
+    ("mbfitsansZ",            "𝙕", Alpha),
+    ("mbfitsansZeta",         "𝞕", Alpha),
+    ("mbfitsansa",            "𝙖", Alpha),
+    ("mbfitsansalpha",        "𝞪", Alpha),
+    ("mbfitsansb",            "𝙗", Alpha),
+    ("mbfitsansbeta",         "𝞫", Alpha),
+    ("mbfitsansc",            "𝙘", Alpha),
+    ("mbfitsanschi",          "𝟀", Alpha),
+    ("mbfitsansd",            "𝙙", Alpha),
+    ("mbfitsansdelta",        "𝞭", Alpha),
+    ("mbfitsanse",            "𝙚", Alpha),
+    ("mbfitsansepsilon",      "𝟄", Alpha),
+    ("mbfitsanseta",          "𝞰", Alpha),
+    ("mbfitsansf",            "𝙛", Alpha),
+    ("mbfitsansg",            "𝙜", Alpha),
+    ("mbfitsansgamma",        "𝞬", Alpha),
+    ("mbfitsansh",            "𝙝", Alpha),
+    ("mbfitsansi",            "𝙞", Alpha),
+    ("mbfitsansiota",         "𝞲", Alpha),
+    ("mbfitsansj",            "𝙟", Alpha),
+    ("mbfitsansk",            "𝙠", Alpha),
+    ("mbfitsanskappa",        "𝞳", Alpha),
+    ("mbfitsansl",            "𝙡", Alpha),
+    ("mbfitsanslambda",       "𝞴", Alpha),
+    ("mbfitsansm",            "𝙢", Alpha),
+    ("mbfitsansmu",           "𝞵", Alpha),
+    ("mbfitsansn",            "𝙣", Alpha),
+    ("mbfitsansnabla",        "𝞩", Alpha),
+    ("mbfitsansnu",           "𝞶", Alpha),
+    ("mbfitsanso",            "𝙤", Alpha),
+    ("mbfitsansomega",        "𝟂", Alpha),
+    ("mbfitsansomicron",      "𝞸", Alpha),
+    ("mbfitsansp",            "𝙥", Alpha),
+    ("mbfitsanspartial",      "𝟃", Alpha),
+    ("mbfitsansphi",          "𝟇", Alpha),
+    ("mbfitsanspi",           "𝞹", Alpha),
+    ("mbfitsanspsi",          "𝟁", Alpha),
+    ("mbfitsansq",            "𝙦", Alpha),
+    ("mbfitsansr",            "𝙧", Alpha),
+    ("mbfitsansrho",          "𝞺", Alpha),
+    ("mbfitsanss",            "𝙨", Alpha),
+    ("mbfitsanssigma",        "𝞼", Alpha),
+    ("mbfitsanst",            "𝙩", Alpha),
+    ("mbfitsanstau",          "𝞽", Alpha),
+    ("mbfitsanstheta",        "𝞱", Alpha),
+    ("mbfitsansu",            "𝙪", Alpha),
+    ("mbfitsansupsilon",      "𝞾", Alpha),
+    ("mbfitsansv",            "𝙫", Alpha),
+    ("mbfitsansvarTheta",     "𝞡", Alpha),
+    ("mbfitsansvarepsilon",   "𝞮", Alpha),
+    ("mbfitsansvarkappa",     "𝟆", Alpha),
+    ("mbfitsansvarphi",       "𝞿", Alpha),
+    ("mbfitsansvarpi",        "𝟉", Alpha),
+    ("mbfitsansvarrho",       "𝟈", Alpha),
+    ("mbfitsansvarsigma",     "𝞻", Alpha),
+    ("mbfitsansvartheta",     "𝟅", Alpha),
+    ("mbfitsansw",            "𝙬", Alpha),
+    ("mbfitsansx",            "𝙭", Alpha),
+    ("mbfitsansxi",           "𝞷", Alpha),
+    ("mbfitsansy",            "𝙮", Alpha),
+    ("mbfitsansz",            "𝙯", Alpha),
+    ("mbfitsanszeta",         "𝞯", Alpha),
+    ("mbfitsigma",            "𝝈", Alpha),
+    ("mbfitt",                "𝒕", Alpha),
+    ("mbfittau",              "𝝉", Alpha),
+    ("mbfittheta",            "𝜽", Alpha),
+    ("mbfitu",                "𝒖", Alpha),
+    ("mbfitupsilon",          "𝝊", Alpha),
+    ("mbfitv",                "𝒗", Alpha),
+    ("mbfitvarTheta",         "𝜭", Alpha),
+    ("mbfitvarepsilon",       "𝜺", Alpha),
+    ("mbfitvarkappa",         "𝝒", Alpha),
+    ("mbfitvarphi",           "𝝋", Alpha),
+    ("mbfitvarpi",            "𝝕", Alpha),
+    ("mbfitvarrho",           "𝝔", Alpha),
+    ("mbfitvarsigma",         "𝝇", Alpha),
+    ("mbfitvartheta",         "𝝑", Alpha),
+    ("mbfitw",                "𝒘", Alpha),
+    ("mbfitx",                "𝒙", Alpha),
+    ("mbfitxi",               "𝝃", Alpha),
+    ("mbfity",                "𝒚", Alpha),
+    ("mbfitz",                "𝒛", Alpha),
+    ("mbfitzeta",             "𝜻", Alpha),
+    ("mbfj",                  "𝐣", Alpha),
+    ("mbfk",                  "𝐤", Alpha),
+    ("mbfkappa",              "𝛋", Alpha),
+    ("mbfl",                  "𝐥", Alpha),
+    ("mbflambda",             "𝛌", Alpha),
+    ("mbfm",                  "𝐦", Alpha),
+    ("mbfmu",                 "𝛍", Alpha),
+    ("mbfn",                  "𝐧", Alpha),
+    ("mbfnabla",              "𝛁", Alpha),
+    ("mbfnine",               "𝟗", Ord),
+    ("mbfnu",                 "𝛎", Alpha),
+    ("mbfo",                  "𝐨", Alpha),
+    ("mbfomega",              "𝛚", Alpha),
+    ("mbfomicron",            "𝛐", Alpha),
+    ("mbfone",                "𝟏", Ord),
+    ("mbfp",                  "𝐩", Alpha),
+    ("mbfpartial",            "𝛛", Alpha),
+    ("mbfphi",                "𝛟", Alpha),
+    ("mbfpi",                 "𝛑", Alpha),
+    ("mbfpsi",                "𝛙", Alpha),
+    ("mbfq",                  "𝐪", Alpha),
+    ("mbfr",                  "𝐫", Alpha),
+    ("mbfrho",                "𝛒", Alpha),
+    ("mbfs",                  "𝐬", Alpha),
+    ("mbfsansA",              "𝗔", Alpha),
+    ("mbfsansAlpha",          "𝝖", Alpha),
+    ("mbfsansB",              "𝗕", Alpha),
+    ("mbfsansBeta",           "𝝗", Alpha),
+    ("mbfsansC",              "𝗖", Alpha),
+    ("mbfsansChi",            "𝝬", Alpha),
+    ("mbfsansD",              "𝗗", Alpha),
+    ("mbfsansDelta",          "𝝙", Alpha),
+    ("mbfsansE",              "𝗘", Alpha),
+    ("mbfsansEpsilon",        "𝝚", Alpha),
+    ("mbfsansEta",            "𝝜", Alpha),
+    ("mbfsansF",              "𝗙", Alpha),
+    ("mbfsansG",              "𝗚", Alpha),
+    ("mbfsansGamma",          "𝝘", Alpha),
+    ("mbfsansH",              "𝗛", Alpha),
+    ("mbfsansI",              "𝗜", Alpha),
+    ("mbfsansIota",           "𝝞", Alpha),
+    ("mbfsansJ",              "𝗝", Alpha),
+    ("mbfsansK",              "𝗞", Alpha),
+    ("mbfsansKappa",          "𝝟", Alpha),
+    ("mbfsansL",              "𝗟", Alpha),
+    ("mbfsansLambda",         "𝝠", Alpha),
+    ("mbfsansM",              "𝗠", Alpha),
+    ("mbfsansMu",             "𝝡", Alpha),
+    ("mbfsansN",              "𝗡", Alpha),
+    ("mbfsansNu",             "𝝢", Alpha),
+    ("mbfsansO",              "𝗢", Alpha),
+    ("mbfsansOmega",          "𝝮", Alpha),
+    ("mbfsansOmicron",        "𝝤", Alpha),
+    ("mbfsansP",              "𝗣", Alpha),
+    ("mbfsansPhi",            "𝝫", Alpha),
+    ("mbfsansPi",             "𝝥", Alpha),
+    ("mbfsansPsi",            "𝝭", Alpha),
+    ("mbfsansQ",              "𝗤", Alpha),
+    ("mbfsansR",              "𝗥", Alpha),
+    ("mbfsansRho",            "𝝦", Alpha),
+    ("mbfsansS",              "𝗦", Alpha),
+    ("mbfsansSigma",          "𝝨", Alpha),
+    ("mbfsansT",              "𝗧", Alpha),
+    ("mbfsansTau",            "𝝩", Alpha),
+    ("mbfsansTheta",          "𝝝", Alpha),
+    ("mbfsansU",              "𝗨", Alpha),
+    ("mbfsansUpsilon",        "𝝪", Alpha),
+    ("mbfsansV",              "𝗩", Alpha),
+    ("mbfsansW",              "𝗪", Alpha),
+    ("mbfsansX",              "𝗫", Alpha),
+    ("mbfsansXi",             "𝝣", Alpha),
+    ("mbfsansY",              "𝗬", Alpha),
+    ("mbfsansZ",              "𝗭", Alpha),
+    ("mbfsansZeta",           "𝝛", Alpha),
+    ("mbfsansa",              "𝗮", Alpha),
+    ("mbfsansalpha",          "𝝰", Alpha),
+    ("mbfsansb",              "𝗯", Alpha),
+    ("mbfsansbeta",           "𝝱", Alpha),
+    ("mbfsansc",              "𝗰", Alpha),
+    ("mbfsanschi",            "𝞆", Alpha),
+    ("mbfsansd",              "𝗱", Alpha),
+    ("mbfsansdelta",          "𝝳", Alpha),
+    ("mbfsanse",              "𝗲", Alpha),
+    ("mbfsanseight",          "𝟴", Ord),
+    ("mbfsansepsilon",        "𝞊", Alpha),
+    ("mbfsanseta",            "𝝶", Alpha),
+    ("mbfsansf",              "𝗳", Alpha),
+    ("mbfsansfive",           "𝟱", Ord),
+    ("mbfsansfour",           "𝟰", Ord),
+    ("mbfsansg",              "𝗴", Alpha),
+    ("mbfsansgamma",          "𝝲", Alpha),
+    ("mbfsansh",              "𝗵", Alpha),
+    ("mbfsansi",              "𝗶", Alpha),
+    ("mbfsansiota",           "𝝸", Alpha),
+    ("mbfsansj",              "𝗷", Alpha),
+    ("mbfsansk",              "𝗸", Alpha),
+    ("mbfsanskappa",          "𝝹", Alpha),
+    ("mbfsansl",              "𝗹", Alpha),
+    ("mbfsanslambda",         "𝝺", Alpha),
+    ("mbfsansm",              "𝗺", Alpha),
+    ("mbfsansmu",             "𝝻", Alpha),
+    ("mbfsansn",              "𝗻", Alpha),
+    ("mbfsansnabla",          "𝝯", Alpha),
+    ("mbfsansnine",           "𝟵", Ord),
+    ("mbfsansnu",             "𝝼", Alpha),
+    ("mbfsanso",              "𝗼", Alpha),
+    ("mbfsansomega",          "𝞈", Alpha),
+    ("mbfsansomicron",        "𝝾", Alpha),
+    ("mbfsansone",            "𝟭", Ord),
+    ("mbfsansp",              "𝗽", Alpha),
+    ("mbfsanspartial",        "𝞉", Alpha),
+    ("mbfsansphi",            "𝞍", Alpha),
+    ("mbfsanspi",             "𝝿", Alpha),
+    ("mbfsanspsi",            "𝞇", Alpha),
+    ("mbfsansq",              "𝗾", Alpha),
+    ("mbfsansr",              "𝗿", Alpha),
+    ("mbfsansrho",            "𝞀", Alpha),
+    ("mbfsanss",              "𝘀", Alpha),
+    ("mbfsansseven",          "𝟳", Ord),
+    ("mbfsanssigma",          "𝞂", Alpha),
+    ("mbfsanssix",            "𝟲", Ord),
+    ("mbfsanst",              "𝘁", Alpha),
+    ("mbfsanstau",            "𝞃", Alpha),
+    ("mbfsanstheta",          "𝝷", Alpha),
+    ("mbfsansthree",          "𝟯", Ord),
+    ("mbfsanstwo",            "𝟮", Ord),
+    ("mbfsansu",              "𝘂", Alpha),
+    ("mbfsansupsilon",        "𝞄", Alpha),
+    ("mbfsansv",              "𝘃", Alpha),
+    ("mbfsansvarTheta",       "𝝧", Alpha),
+    ("mbfsansvarepsilon",     "𝝴", Alpha),
+    ("mbfsansvarkappa",       "𝞌", Alpha),
+    ("mbfsansvarphi",         "𝞅", Alpha),
+    ("mbfsansvarpi",          "𝞏", Alpha),
+    ("mbfsansvarrho",         "𝞎", Alpha),
+    ("mbfsansvarsigma",       "𝞁", Alpha),
+    ("mbfsansvartheta",       "𝞋", Alpha),
+    ("mbfsansw",              "𝘄", Alpha),
+    ("mbfsansx",              "𝘅", Alpha),
+    ("mbfsansxi",             "𝝽", Alpha),
+    ("mbfsansy",              "𝘆", Alpha),
+    ("mbfsansz",              "𝘇", Alpha),
+    ("mbfsanszero",           "𝟬", Ord),
+    ("mbfsanszeta",           "𝝵", Alpha),
+    ("mbfscrA",               "𝓐", Alpha),
+    ("mbfscrB",               "𝓑", Alpha),
+    ("mbfscrC",               "𝓒", Alpha),
+    ("mbfscrD",               "𝓓", Alpha),
+    ("mbfscrE",               "𝓔", Alpha),
+    ("mbfscrF",               "𝓕", Alpha),
+    ("mbfscrG",               "𝓖", Alpha),
+    ("mbfscrH",               "𝓗", Alpha),
+    ("mbfscrI",               "𝓘", Alpha),
+    ("mbfscrJ",               "𝓙", Alpha),
+    ("mbfscrK",               "𝓚", Alpha),
+    ("mbfscrL",               "𝓛", Alpha),
+    ("mbfscrM",               "𝓜", Alpha),
+    ("mbfscrN",               "𝓝", Alpha),
+    ("mbfscrO",               "𝓞", Alpha),
+    ("mbfscrP",               "𝓟", Alpha),
+    ("mbfscrQ",               "𝓠", Alpha),
+    ("mbfscrR",               "𝓡", Alpha),
+    ("mbfscrS",               "𝓢", Alpha),
+    ("mbfscrT",               "𝓣", Alpha),
+    ("mbfscrU",               "𝓤", Alpha),
+    ("mbfscrV",               "𝓥", Alpha),
+    ("mbfscrW",               "𝓦", Alpha),
+    ("mbfscrX",               "𝓧", Alpha),
+    ("mbfscrY",               "𝓨", Alpha),
+    ("mbfscrZ",               "𝓩", Alpha),
+    ("mbfscra",               "𝓪", Alpha),
+    ("mbfscrb",               "𝓫", Alpha),
+    ("mbfscrc",               "𝓬", Alpha),
+    ("mbfscrd",               "𝓭", Alpha),
+    ("mbfscre",               "𝓮", Alpha),
+    ("mbfscrf",               "𝓯", Alpha),
+    ("mbfscrg",               "𝓰", Alpha),
+    ("mbfscrh",               "𝓱", Alpha),
+    ("mbfscri",               "𝓲", Alpha),
+    ("mbfscrj",               "𝓳", Alpha),
+    ("mbfscrk",               "𝓴", Alpha),
+    ("mbfscrl",               "𝓵", Alpha),
+    ("mbfscrm",               "𝓶", Alpha),
+    ("mbfscrn",               "𝓷", Alpha),
+    ("mbfscro",               "𝓸", Alpha),
+    ("mbfscrp",               "𝓹", Alpha),
+    ("mbfscrq",               "𝓺", Alpha),
+    ("mbfscrr",               "𝓻", Alpha),
+    ("mbfscrs",               "𝓼", Alpha),
+    ("mbfscrt",               "𝓽", Alpha),
+    ("mbfscru",               "𝓾", Alpha),
+    ("mbfscrv",               "𝓿", Alpha),
+    ("mbfscrw",               "𝔀", Alpha),
+    ("mbfscrx",               "𝔁", Alpha),
+    ("mbfscry",               "𝔂", Alpha),
+    ("mbfscrz",               "𝔃", Alpha),
+    ("mbfseven",              "𝟕", Ord),
+    ("mbfsigma",              "𝛔", Alpha),
+    ("mbfsix",                "𝟔", Ord),
+    ("mbft",                  "𝐭", Alpha),
+    ("mbftau",                "𝛕", Alpha),
+    ("mbftheta",              "𝛉", Alpha),
+    ("mbfthree",              "𝟑", Ord),
+    ("mbftwo",                "𝟐", Ord),
+    ("mbfu",                  "𝐮", Alpha),
+    ("mbfupsilon",            "𝛖", Alpha),
+    ("mbfv",                  "𝐯", Alpha),
+    ("mbfvarTheta",           "𝚹", Alpha),
+    ("mbfvarepsilon",         "𝛆", Alpha),
+    ("mbfvarkappa",           "𝛞", Alpha),
+    ("mbfvarphi",             "𝛗", Alpha),
+    ("mbfvarpi",              "𝛡", Alpha),
+    ("mbfvarrho",             "𝛠", Alpha),
+    ("mbfvarsigma",           "𝛓", Alpha),
+    ("mbfvartheta",           "𝛝", Alpha),
+    ("mbfw",                  "𝐰", Alpha),
+    ("mbfx",                  "𝐱", Alpha),
+    ("mbfxi",                 "𝛏", Alpha),
+    ("mbfy",                  "𝐲", Alpha),
+    ("mbfz",                  "𝐳", Alpha),
+    ("mbfzero",               "𝟎", Ord),
+    ("mbfzeta",               "𝛇", Alpha),
+    ("mdblkcircle",           "⚫", Ord),
+    ("mdlgblkcircle",         "●", Ord),
+    ("mdlgblkdiamond",        "◆", Ord),
+    ("mdlgblksquare",         "■", Ord),
+    ("mdlgwhtcircle",         "○", Bin),
+    ("mdlgwhtdiamond",        "◇", Ord),
+    ("mdlgwhtsquare",         "□", Ord),
+    ("mdwhtcircle",           "⚪", Ord),
+    ("measeq",                "≞", Rel),
+    ("measuredangle",         "∡", Ord),
+    ("medbullet",             "⚫", Ord),
+    ("medcirc",               "⚪", Ord),
+    ("mercury",               "☿", Ord),
+    ("mfrakA",                "𝔄", Alpha),
+    ("mfrakB",                "𝔅", Alpha),
+    ("mfrakC",                "ℭ", Alpha),
+    ("mfrakD",                "𝔇", Alpha),
+    ("mfrakE",                "𝔈", Alpha),
+    ("mfrakF",                "𝔉", Alpha),
+    ("mfrakG",                "𝔊", Alpha),
+    ("mfrakH",                "ℌ", Alpha),
+    ("mfrakI",                "ℑ", Alpha),
+    ("mfrakJ",                "𝔍", Alpha),
+    ("mfrakK",                "𝔎", Alpha),
+    ("mfrakL",                "𝔏", Alpha),
+    ("mfrakM",                "𝔐", Alpha),
+    ("mfrakN",                "𝔑", Alpha),
+    ("mfrakO",                "𝔒", Alpha),
+    ("mfrakP",                "𝔓", Alpha),
+    ("mfrakQ",                "𝔔", Alpha),
+    ("mfrakR",                "ℜ", Alpha),
+    ("mfrakS",                "𝔖", Alpha),
+    ("mfrakT",                "𝔗", Alpha),
+    ("mfrakU",                "𝔘", Alpha),
+    ("mfrakV",                "𝔙", Alpha),
+    ("mfrakW",                "𝔚", Alpha),
+    ("mfrakX",                "𝔛", Alpha),
+    ("mfrakY",                "𝔜", Alpha),
+    ("mfrakZ",                "ℨ", Alpha),
+    ("mfraka",                "𝔞", Alpha),
+    ("mfrakb",                "𝔟", Alpha),
+    ("mfrakc",                "𝔠", Alpha),
+    ("mfrakd",                "𝔡", Alpha),
+    ("mfrake",                "𝔢", Alpha),
+    ("mfrakf",                "𝔣", Alpha),
+    ("mfrakg",                "𝔤", Alpha),
+    ("mfrakh",                "𝔥", Alpha),
+    ("mfraki",                "𝔦", Alpha),
+    ("mfrakj",                "𝔧", Alpha),
+    ("mfrakk",                "𝔨", Alpha),
+    ("mfrakl",                "𝔩", Alpha),
+    ("mfrakm",                "𝔪", Alpha),
+    ("mfrakn",                "𝔫", Alpha),
+    ("mfrako",                "𝔬", Alpha),
+    ("mfrakp",                "𝔭", Alpha),
+    ("mfrakq",                "𝔮", Alpha),
+    ("mfrakr",                "𝔯", Alpha),
+    ("mfraks",                "𝔰", Alpha),
+    ("mfrakt",                "𝔱", Alpha),
+    ("mfraku",                "𝔲", Alpha),
+    ("mfrakv",                "𝔳", Alpha),
+    ("mfrakw",                "𝔴", Alpha),
+    ("mfrakx",                "𝔵", Alpha),
+    ("mfraky",                "𝔶", Alpha),
+    ("mfrakz",                "𝔷", Alpha),
+    ("mho",                   "℧", Ord),
+    ("mid",                   "∣", Rel),
+    ("mitA",                  "𝐴", Alpha),
+    ("mitAlpha",              "𝛢", Alpha),
+    ("mitB",                  "𝐵", Alpha),
+    ("mitBeta",               "𝛣", Alpha),
+    ("mitC",                  "𝐶", Alpha),
+    ("mitChi",                "𝛸", Alpha),
+    ("mitD",                  "𝐷", Alpha),
+    ("mitDelta",              "𝛥", Alpha),
+    ("mitE",                  "𝐸", Alpha),
+    ("mitEpsilon",            "𝛦", Alpha),
+    ("mitEta",                "𝛨", Alpha),
+    ("mitF",                  "𝐹", Alpha),
+    ("mitG",                  "𝐺", Alpha),
+    ("mitGamma",              "𝛤", Alpha),
+    ("mitH",                  "𝐻", Alpha),
+    ("mitI",                  "𝐼", Alpha),
+    ("mitIota",               "𝛪", Alpha),
+    ("mitJ",                  "𝐽", Alpha),
+    ("mitK",                  "𝐾", Alpha),
+    ("mitKappa",              "𝛫", Alpha),
+    ("mitL",                  "𝐿", Alpha),
+    ("mitLambda",             "𝛬", Alpha),
+    ("mitM",                  "𝑀", Alpha),
+    ("mitMu",                 "𝛭", Alpha),
+    ("mitN",                  "𝑁", Alpha),
+    ("mitNu",                 "𝛮", Alpha),
+    ("mitO",                  "𝑂", Alpha),
+    ("mitOmega",              "𝛺", Alpha),
+    ("mitOmicron",            "𝛰", Alpha),
+    ("mitP",                  "𝑃", Alpha),
+    ("mitPhi",                "𝛷", Alpha),
+    ("mitPi",                 "𝛱", Alpha),
+    ("mitPsi",                "𝛹", Alpha),
+    ("mitQ",                  "𝑄", Alpha),
+    ("mitR",                  "𝑅", Alpha),
+    ("mitRho",                "𝛲", Alpha),
+    ("mitS",                  "𝑆", Alpha),
+    ("mitSigma",              "𝛴", Alpha),
+    ("mitT",                  "𝑇", Alpha),
+    ("mitTau",                "𝛵", Alpha),
+    ("mitTheta",              "𝛩", Alpha),
+    ("mitU",                  "𝑈", Alpha),
+    ("mitUpsilon",            "𝛶", Alpha),
+    ("mitV",                  "𝑉", Alpha),
+    ("mitW",                  "𝑊", Alpha),
+    ("mitX",                  "𝑋", Alpha),
+    ("mitXi",                 "𝛯", Alpha),
+    ("mitY",                  "𝑌", Alpha),
+    ("mitZ",                  "𝑍", Alpha),
+    ("mitZeta",               "𝛧", Alpha),
+    ("mita",                  "𝑎", Alpha),
+    ("mitalpha",              "𝛼", Alpha),
+    ("mitb",                  "𝑏", Alpha),
+    ("mitbeta",               "𝛽", Alpha),
+    ("mitc",                  "𝑐", Alpha),
+    ("mitchi",                "𝜒", Alpha),
+    ("mitd",                  "𝑑", Alpha),
+    ("mitdelta",              "𝛿", Alpha),
+    ("mite",                  "𝑒", Alpha),
+    ("mitepsilon",            "𝜖", Alpha),
+    ("miteta",                "𝜂", Alpha),
+    ("mitf",                  "𝑓", Alpha),
+    ("mitg",                  "𝑔", Alpha),
+    ("mitgamma",              "𝛾", Alpha),
+    ("mith",                  "ℎ", Alpha),
+    ("miti",                  "𝑖", Alpha),
+    ("mitiota",               "𝜄", Alpha),
+    ("mitj",                  "𝑗", Alpha),
+    ("mitk",                  "𝑘", Alpha),
+    ("mitkappa",              "𝜅", Alpha),
+    ("mitl",                  "𝑙", Alpha),
+    ("mitlambda",             "𝜆", Alpha),
+    ("mitm",                  "𝑚", Alpha),
+    ("mitmu",                 "𝜇", Alpha),
+    ("mitn",                  "𝑛", Alpha),
+    ("mitnabla",              "𝛻", Alpha),
+    ("mitnu",                 "𝜈", Alpha),
+    ("mito",                  "𝑜", Alpha),
+    ("mitomega",              "𝜔", Alpha),
+    ("mitomicron",            "𝜊", Alpha),
+    ("mitp",                  "𝑝", Alpha),
+    ("mitpartial",            "𝜕", Alpha),
+    ("mitphi",                "𝜙", Alpha),
+    ("mitpi",                 "𝜋", Alpha),
+    ("mitpsi",                "𝜓", Alpha),
+    ("mitq",                  "𝑞", Alpha),
+    ("mitr",                  "𝑟", Alpha),
+    ("mitrho",                "𝜌", Alpha),
+    ("mits",                  "𝑠", Alpha),
+    ("mitsansA",              "𝘈", Alpha),
+    ("mitsansB",              "𝘉", Alpha),
+    ("mitsansC",              "𝘊", Alpha),
+    ("mitsansD",              "𝘋", Alpha),
+    ("mitsansE",              "𝘌", Alpha),
+    ("mitsansF",              "𝘍", Alpha),
+    ("mitsansG",              "𝘎", Alpha),
+    ("mitsansH",              "𝘏", Alpha),
+    ("mitsansI",              "𝘐", Alpha),
+    ("mitsansJ",              "𝘑", Alpha),
+    ("mitsansK",              "𝘒", Alpha),
+    ("mitsansL",              "𝘓", Alpha),
+    ("mitsansM",              "𝘔", Alpha),
+    ("mitsansN",              "𝘕", Alpha),
+    ("mitsansO",              "𝘖", Alpha),
+    ("mitsansP",              "𝘗", Alpha),
+    ("mitsansQ",              "𝘘", Alpha),
+    ("mitsansR",              "𝘙", Alpha),
+    ("mitsansS",              "𝘚", Alpha),
+    ("mitsansT",              "𝘛", Alpha),
+    ("mitsansU",              "𝘜", Alpha),
+    ("mitsansV",              "𝘝", Alpha),
+    ("mitsansW",              "𝘞", Alpha),
+    ("mitsansX",              "𝘟", Alpha),
+    ("mitsansY",              "𝘠", Alpha),
+    ("mitsansZ",              "𝘡", Alpha),
+    ("mitsansa",              "𝘢", Alpha),
+    ("mitsansb",              "𝘣", Alpha),
+    ("mitsansc",              "𝘤", Alpha),
+    ("mitsansd",              "𝘥", Alpha),
+    ("mitsanse",              "𝘦", Alpha),
+    ("mitsansf",              "𝘧", Alpha),
+    ("mitsansg",              "𝘨", Alpha),
+    ("mitsansh",              "𝘩", Alpha),
+    ("mitsansi",              "𝘪", Alpha),
+    ("mitsansj",              "𝘫", Alpha),
+    ("mitsansk",              "𝘬", Alpha),
+    ("mitsansl",              "𝘭", Alpha),
+    ("mitsansm",              "𝘮", Alpha),
+    ("mitsansn",              "𝘯", Alpha),
+    ("mitsanso",              "𝘰", Alpha),
+    ("mitsansp",              "𝘱", Alpha),
+    ("mitsansq",              "𝘲", Alpha),
+    ("mitsansr",              "𝘳", Alpha),
+    ("mitsanss",              "𝘴", Alpha),
+    ("mitsanst",              "𝘵", Alpha),
+    ("mitsansu",              "𝘶", Alpha),
+    ("mitsansv",              "𝘷", Alpha),
+    ("mitsansw",              "𝘸", Alpha),
+    ("mitsansx",              "𝘹", Alpha),
+    ("mitsansy",              "𝘺", Alpha),
+    ("mitsansz",              "𝘻", Alpha),
+    ("mitsigma",              "𝜎", Alpha),
+    ("mitt",                  "𝑡", Alpha),
+    ("mittau",                "𝜏", Alpha),
+    ("mittheta",              "𝜃", Alpha),
+    ("mitu",                  "𝑢", Alpha),
+    ("mitupsilon",            "𝜐", Alpha),
+    ("mitv",                  "𝑣", Alpha),
+    ("mitvarTheta",           "𝛳", Alpha),
+    ("mitvarepsilon",         "𝜀", Alpha),
+    ("mitvarkappa",           "𝜘", Alpha),
+    ("mitvarphi",             "𝜑", Alpha),
+    ("mitvarpi",              "𝜛", Alpha),
+    ("mitvarrho",             "𝜚", Alpha),
+    ("mitvarsigma",           "𝜍", Alpha),
+    ("mitvartheta",           "𝜗", Alpha),
+    ("mitw",                  "𝑤", Alpha),
+    ("mitx",                  "𝑥", Alpha),
+    ("mitxi",                 "𝜉", Alpha),
+    ("mity",                  "𝑦", Alpha),
+    ("mitz",                  "𝑧", Alpha),
+    ("mitzeta",               "𝜁", Alpha),
+    ("mlcp",                  "⫛", Rel),
+    ("models",                "⊧", Rel),
+    ("modtwosum",             "⨊", Op),
+    ("mp",                    "∓", Bin),
+    ("msansA",                "𝖠", Alpha),
+    ("msansB",                "𝖡", Alpha),
+    ("msansC",                "𝖢", Alpha),
+    ("msansD",                "𝖣", Alpha),
+    ("msansE",                "𝖤", Alpha),
+    ("msansF",                "𝖥", Alpha),
+    ("msansG",                "𝖦", Alpha),
+    ("msansH",                "𝖧", Alpha),
+    ("msansI",                "𝖨", Alpha),
+    ("msansJ",                "𝖩", Alpha),
+    ("msansK",                "𝖪", Alpha),
+    ("msansL",                "𝖫", Alpha),
+    ("msansM",                "𝖬", Alpha),
+    ("msansN",                "𝖭", Alpha),
+    ("msansO",                "𝖮", Alpha),
+    ("msansP",                "𝖯", Alpha),
+    ("msansQ",                "𝖰", Alpha),
+    ("msansR",                "𝖱", Alpha),
+    ("msansS",                "𝖲", Alpha),
+    ("msansT",                "𝖳", Alpha),
+    ("msansU",                "𝖴", Alpha),
+    ("msansV",                "𝖵", Alpha),
+    ("msansW",                "𝖶", Alpha),
+    ("msansX",                "𝖷", Alpha),
+    ("msansY",                "𝖸", Alpha),
+    ("msansZ",                "𝖹", Alpha),
+    ("msansa",                "𝖺", Alpha),
+    ("msansb",                "𝖻", Alpha),
+    ("msansc",                "𝖼", Alpha),
+    ("msansd",                "𝖽", Alpha),
+    ("msanse",                "𝖾", Alpha),
+    ("msanseight",            "𝟪", Ord),
+    ("msansf",                "𝖿", Alpha),
+    ("msansfive",             "𝟧", Ord),
+    ("msansfour",             "𝟦", Ord),
+    ("msansg",                "𝗀", Alpha),
+    ("msansh",                "𝗁", Alpha),
+    ("msansi",                "𝗂", Alpha),
+    ("msansj",                "𝗃", Alpha),
+    ("msansk",                "𝗄", Alpha),
+    ("msansl",                "𝗅", Alpha),
+    ("msansm",                "𝗆", Alpha),
+    ("msansn",                "𝗇", Alpha),
+    ("msansnine",             "𝟫", Ord),
+    ("msanso",                "𝗈", Alpha),
+    ("msansone",              "𝟣", Ord),
+    ("msansp",                "𝗉", Alpha),
+    ("msansq",                "𝗊", Alpha),
+    ("msansr",                "𝗋", Alpha),
+    ("msanss",                "𝗌", Alpha),
+    ("msansseven",            "𝟩", Ord),
+    ("msanssix",              "𝟨", Ord),
+    ("msanst",                "𝗍", Alpha),
+    ("msansthree",            "𝟥", Ord),
+    ("msanstwo",              "𝟤", Ord),
+    ("msansu",                "𝗎", Alpha),
+    ("msansv",                "𝗏", Alpha),
+    ("msansw",                "𝗐", Alpha),
+    ("msansx",                "𝗑", Alpha),
+    ("msansy",                "𝗒", Alpha),
+    ("msansz",                "𝗓", Alpha),
+    ("msanszero",             "𝟢", Ord),
+    ("mscrA",                 "𝒜", Alpha),
+    ("mscrB",                 "ℬ", Alpha),
+    ("mscrC",                 "𝒞", Alpha),
+    ("mscrD",                 "𝒟", Alpha),
+    ("mscrE",                 "ℰ", Alpha),
+    ("mscrF",                 "ℱ", Alpha),
+    ("mscrG",                 "𝒢", Alpha),
+    ("mscrH",                 "ℋ", Alpha),
+    ("mscrI",                 "ℐ", Alpha),
+    ("mscrJ",                 "𝒥", Alpha),
+    ("mscrK",                 "𝒦", Alpha),
+    ("mscrL",                 "ℒ", Alpha),
+    ("mscrM",                 "ℳ", Alpha),
+    ("mscrN",                 "𝒩", Alpha),
+    ("mscrO",                 "𝒪", Alpha),
+    ("mscrP",                 "𝒫", Alpha),
+    ("mscrQ",                 "𝒬", Alpha),
+    ("mscrR",                 "ℛ", Alpha),
+    ("mscrS",                 "𝒮", Alpha),
+    ("mscrT",                 "𝒯", Alpha),
+    ("mscrU",                 "𝒰", Alpha),
+    ("mscrV",                 "𝒱", Alpha),
+    ("mscrW",                 "𝒲", Alpha),
+    ("mscrX",                 "𝒳", Alpha),
+    ("mscrY",                 "𝒴", Alpha),
+    ("mscrZ",                 "𝒵", Alpha),
+    ("mscra",                 "𝒶", Alpha),
+    ("mscrb",                 "𝒷", Alpha),
+    ("mscrc",                 "𝒸", Alpha),
+    ("mscrd",                 "𝒹", Alpha),
+    ("mscre",                 "ℯ", Alpha),
+    ("mscrf",                 "𝒻", Alpha),
+    ("mscrg",                 "ℊ", Alpha),
+    ("mscrh",                 "𝒽", Alpha),
+    ("mscri",                 "𝒾", Alpha),
+    ("mscrj",                 "𝒿", Alpha),
+    ("mscrk",                 "𝓀", Alpha),
+    ("mscrl",                 "𝓁", Alpha),
+    ("mscrm",                 "𝓂", Alpha),
+    ("mscrn",                 "𝓃", Alpha),
+    ("mscro",                 "ℴ", Alpha),
+    ("mscrp",                 "𝓅", Alpha),
+    ("mscrq",                 "𝓆", Alpha),
+    ("mscrr",                 "𝓇", Alpha),
+    ("mscrs",                 "𝓈", Alpha),
+    ("mscrt",                 "𝓉", Alpha),
+    ("mscru",                 "𝓊", Alpha),
+    ("mscrv",                 "𝓋", Alpha),
+    ("mscrw",                 "𝓌", Alpha),
+    ("mscrx",                 "𝓍", Alpha),
+    ("mscry",                 "𝓎", Alpha),
+    ("mscrz",                 "𝓏", Alpha),
+    ("mttA",                  "𝙰", Alpha),
+    ("mttB",                  "𝙱", Alpha),
+    ("mttC",                  "𝙲", Alpha),
+    ("mttD",                  "𝙳", Alpha),
+    ("mttE",                  "𝙴", Alpha),
+    ("mttF",                  "𝙵", Alpha),
+    ("mttG",                  "𝙶", Alpha),
+    ("mttH",                  "𝙷", Alpha),
+    ("mttI",                  "𝙸", Alpha),
+    ("mttJ",                  "𝙹", Alpha),
+    ("mttK",                  "𝙺", Alpha),
+    ("mttL",                  "𝙻", Alpha),
+    ("mttM",                  "𝙼", Alpha),
+    ("mttN",                  "𝙽", Alpha),
+    ("mttO",                  "𝙾", Alpha),
+    ("mttP",                  "𝙿", Alpha),
+    ("mttQ",                  "𝚀", Alpha),
+    ("mttR",                  "𝚁", Alpha),
+    ("mttS",                  "𝚂", Alpha),
+    ("mttT",                  "𝚃", Alpha),
+    ("mttU",                  "𝚄", Alpha),
+    ("mttV",                  "𝚅", Alpha),
+    ("mttW",                  "𝚆", Alpha),
+    ("mttX",                  "𝚇", Alpha),
+    ("mttY",                  "𝚈", Alpha),
+    ("mttZ",                  "𝚉", Alpha),
+    ("mtta",                  "𝚊", Alpha),
+    ("mttb",                  "𝚋", Alpha),
+    ("mttc",                  "𝚌", Alpha),
+    ("mttd",                  "𝚍", Alpha),
+    ("mtte",                  "𝚎", Alpha),
+    ("mtteight",              "𝟾", Ord),
+    ("mttf",                  "𝚏", Alpha),
+    ("mttfive",               "𝟻", Ord),
+    ("mttfour",               "𝟺", Ord),
+    ("mttg",                  "𝚐", Alpha),
+    ("mtth",                  "𝚑", Alpha),
+    ("mtti",                  "𝚒", Alpha),
+    ("mttj",                  "𝚓", Alpha),
+    ("mttk",                  "𝚔", Alpha),
+    ("mttl",                  "𝚕", Alpha),
+    ("mttm",                  "𝚖", Alpha),
+    ("mttn",                  "𝚗", Alpha),
+    ("mttnine",               "𝟿", Ord),
+    ("mtto",                  "𝚘", Alpha),
+    ("mttone",                "𝟷", Ord),
+    ("mttp",                  "𝚙", Alpha),
+    ("mttq",                  "𝚚", Alpha),
+    ("mttr",                  "𝚛", Alpha),
+    ("mtts",                  "𝚜", Alpha),
+    ("mttseven",              "𝟽", Ord),
+    ("mttsix",                "𝟼", Ord),
+    ("mttt",                  "𝚝", Alpha),
+    ("mttthree",              "𝟹", Ord),
+    ("mtttwo",                "𝟸", Ord),
+    ("mttu",                  "𝚞", Alpha),
+    ("mttv",                  "𝚟", Alpha),
+    ("mttw",                  "𝚠", Alpha),
+    ("mttx",                  "𝚡", Alpha),
+    ("mtty",                  "𝚢", Alpha),
+    ("mttz",                  "𝚣", Alpha),
+    ("mttzero",               "𝟶", Ord),
+    ("mu",                    "μ", Alpha),
+    ("multimap",              "⊸", Rel),
+    ("multimapboth",          "⧟", Rel),
+    ("multimapdotbothA",      "⊶", Rel),
+    ("multimapdotbothB",      "⊷", Rel),
+    ("multimapinv",           "⟜", Rel),
+    ("mupAlpha",              "Α", Alpha),
+    ("mupBeta",               "Β", Alpha),
+    ("mupChi",                "Χ", Alpha),
+    ("mupDelta",              "Δ", Alpha),
+    ("mupEpsilon",            "Ε", Alpha),
+    ("mupEta",                "Η", Alpha),
+    ("mupGamma",              "Γ", Alpha),
+    ("mupIota",               "Ι", Alpha),
+    ("mupKappa",              "Κ", Alpha),
+    ("mupLambda",             "Λ", Alpha),
+    ("mupMu",                 "Μ", Alpha),
+    ("mupNu",                 "Ν", Alpha),
+    ("mupOmega",              "Ω", Alpha),
+    ("mupOmicron",            "Ο", Alpha),
+    ("mupPhi",                "Φ", Alpha),
+    ("mupPi",                 "Π", Alpha),
+    ("mupPsi",                "Ψ", Alpha),
+    ("mupRho",                "Ρ", Alpha),
+    ("mupSigma",              "Σ", Alpha),
+    ("mupTau",                "Τ", Alpha),
+    ("mupTheta",              "Θ", Alpha),
+    ("mupUpsilon",            "Υ", Alpha),
+    ("mupXi",                 "Ξ", Alpha),
+    ("mupZeta",               "Ζ", Alpha),
+    ("mupalpha",              "α", Alpha),
+    ("mupbeta",               "β", Alpha),
+    ("mupchi",                "χ", Alpha),
+    ("mupdelta",              "δ", Alpha),
+    ("mupepsilon",            "ϵ", Alpha),
+    ("mupeta",                "η", Alpha),
+    ("mupgamma",              "γ", Alpha),
+    ("mupiota",               "ι", Alpha),
+    ("mupkappa",              "κ", Alpha),
+    ("muplambda",             "λ", Alpha),
+    ("mupmu",                 "μ", Alpha),
+    ("mupnu",                 "ν", Alpha),
+    ("mupomega",              "ω", Alpha),
+    ("mupomicron",            "ο", Alpha),
+    ("mupphi",                "ϕ", Alpha),
+    ("muppi",                 "π", Alpha),
+    ("muppsi",                "ψ", Alpha),
+    ("muprho",                "ρ", Alpha),
+    ("mupsigma",              "σ", Alpha),
+    ("muptau",                "τ", Alpha),
+    ("muptheta",              "θ", Alpha),
+    ("mupupsilon",            "υ", Alpha),
+    ("mupvarTheta",           "ϴ", Alpha),
+    ("mupvarepsilon",         "ε", Alpha),
+    ("mupvarkappa",           "ϰ", Alpha),
+    ("mupvarphi",             "φ", Alpha),
+    ("mupvarpi",              "ϖ", Alpha),
+    ("mupvarrho",             "ϱ", Alpha),
+    ("mupvarsigma",           "ς", Alpha),
+    ("mupvartheta",           "ϑ", Alpha),
+    ("mupxi",                 "ξ", Alpha),
+    ("mupzeta",               "ζ", Alpha),
+    ("nLeftarrow",            "⇍", Rel),
+    ("nLeftrightarrow",       "⇎", Rel),
+    ("nRightarrow",           "⇏", Rel),
+    ("nVDash",                "⊯", Rel),
+    ("nVdash",                "⊮", Rel),
+    ("nVleftarrow",           "⇺", Rel),
+    ("nVrightarrow",          "⇻", Rel),
+    ("nabla",                 "∇", Ord),
+    ("napprox",               "≉", Rel),
+    ("nasymp",                "≭", Rel),
+    ("natural",               "♮", Ord),
+    ("ncong",                 "≇", Rel),
+    ("ne",                    "≠", Rel),
+    ("nearrow",               "↗", Rel),
+    ("neg",                   "¬", Ord),
+    ("neptune",               "♆", Ord),
+    ("neq",                   "≠", Rel),
+    ("nequiv",                "≢", Rel),
+    ("neuter",                "⚲", Ord),
+    ("nexists",               "∄", Ord),
+    ("ngeq",                  "≱", Rel),
+    ("ngtr",                  "≯", Rel),
+    ("ngtrless",              "≹", Rel),
+    ("ni",                    "∋", Rel),
+    ("nleftarrow",            "↚", Rel),
+    ("nleftrightarrow",       "↮", Rel),
+    ("nleq",                  "≰", Rel),
+    ("nless",                 "≮", Rel),
+    ("nlessgtr",              "≸", Rel),
+    ("nmid",                  "∤", Rel),
+    ("nni",                   "∌", Rel),
+    ("notasymp",              "≭", Rel),
+    ("notbackslash",          "⍀", Ord),
+    ("notin",                 "∉", Rel),
+    ("notni",                 "∌", Rel),
+    ("notowner",              "∌", Rel),
+    ("notslash",              "⌿", Rel),
+    ("nparallel",             "∦", Rel),
+    ("npolint",               "⨔", Op),
+    ("nprec",                 "⊀", Rel),
+    ("npreceq",               "⋠", Rel),
+    ("nrightarrow",           "↛", Rel),
+    ("nsim",                  "≁", Rel),
+    ("nsimeq",                "≄", Rel),
+    ("nsqsubseteq",           "⋢", Rel),
+    ("nsqsupseteq",           "⋣", Rel),
+    ("nsubset",               "⊄", Rel),
+    ("nsubseteq",             "⊈", Rel),
+    ("nsucc",                 "⊁", Rel),
+    ("nsucceq",               "⋡", Rel),
+    ("nsupset",               "⊅", Rel),
+    ("nsupseteq",             "⊉", Rel),
+    ("ntriangleleft",         "⋪", Rel),
+    ("ntrianglelefteq",       "⋬", Rel),
+    ("ntriangleright",        "⋫", Rel),
+    ("ntrianglerighteq",      "⋭", Rel),
+    ("nu",                    "ν", Alpha),
+    ("nvDash",                "⊭", Rel),
+    ("nvartriangleleft",      "⋪", Rel),
+    ("nvartriangleright",     "⋫", Rel),
+    ("nvdash",                "⊬", Rel),
+    ("nvleftarrow",           "⇷", Rel),
+    ("nvrightarrow",          "⇸", Rel),
+    ("nwarrow",               "↖", Rel),
+    ("odot",                  "⊙", Bin),
+    ("oiiint",                "∰", Op),
+    ("oiint",                 "∯", Op),
+    ("oint",                  "∮", Op),
+    ("ointctrclockwise",      "∳", Op),
+    ("ointop",                "∮", Op),
+    ("omega",                 "ω", Alpha),
+    ("ominus",                "⊖", Bin),
+    ("oplus",                 "⊕", Bin),
+    ("origof",                "⊶", Rel),
+    ("oslash",                "⊘", Bin),
+    ("otimes",                "⊗", Bin),
+    ("owns",                  "∋", Rel),
+    ("parallel",              "∥", Rel),
+    ("partial",               "∂", Ord),
+    ("pencil",                "✎", Ord),
+    ("perp",                  "⟂", Rel),
+    ("perps",                 "⫡", Rel),
+    ("phi",                   "ϕ", Alpha),
+    ("pi",                    "π", Alpha),
+    ("pisces",                "♓", Ord),
+    ("pitchfork",             "⋔", Rel),
+    ("pluto",                 "♇", Ord),
+    ("pm",                    "±", Bin),
+    ("pointint",              "⨕", Op),
+    ("pounds",                "£", Ord),
+    ("prec",                  "≺", Rel),
+    ("precapprox",            "⪷", Rel),
+    ("preccurlyeq",           "≼", Rel),
+    ("preceq",                "⪯", Rel),
+    ("preceqq",               "⪳", Rel),
+    ("precnapprox",           "⪹", Rel),
+    ("precneqq",              "⪵", Rel),
+    ("precnsim",              "⋨", Rel),
+    ("precsim",               "≾", Rel),
+    ("prime",                 "′", Ord),
+    ("prod",                  "∏", Op),
+    ("propto",                "∝", Rel),
+    ("prurel",                "⊰", Rel),
+    ("psi",                   "ψ", Alpha),
+    ("quarternote",           "♩", Ord),
+    ("questeq",               "≟", Rel),
+    ("rAngle",                "⟫", Close),
+    ("rBrace",                "⦄", Close),
+    ("rBrack",                "⟧", Close),
+    ("rParen",                "⦆", Close),
+    ("rangle",                "⟩", Close),
+    ("rbag",                  "⟆", Close),
+    ("rblkbrbrak",            "⦘", Close),
+    ("rbrace",                "}", Close),
+    ("rbrack",                "]", Close),
+    ("rbrackubar",            "⦌", Close),
+    ("rbrbrak",               "❳", Close),
+    ("rceil",                 "⌉", Close),
+    ("rcurvyangle",           "⧽", Close),
+    ("recycle",               "♻", Ord),
+    ("restriction",           "↾", Rel),
+    ("rfloor",                "⌋", Close),
+    ("rgroup",                "⟯", Close),
+    ("rhd",                   "⊳", Rel),
+    ("rho",                   "ρ", Alpha),
+    ("rightarrow",            "→", Rel),
+    ("rightarrowtail",        "↣", Rel),
+    ("rightarrowtriangle",    "⇾", Rel),
+    ("rightbarharpoon",       "⥬", Rel),
+    ("rightdasharrow",        "⇢", Rel),
+    ("rightharpoondown",      "⇁", Rel),
+    ("rightharpoonup",        "⇀", Rel),
+    ("rightleftarrows",       "⇄", Rel),
+    ("rightleftharpoon",      "⥋", Rel),
+    ("rightleftharpoons",     "⇌", Rel),
+    ("rightmoon",             "☽", Ord),
+    ("rightouterjoin",        "⟖", Op),
+    ("rightrightarrows",      "⇉", Rel),
+    ("rightrightharpoons",    "⥤", Rel),
+    ("rightslice",            "⪧", Rel),
+    ("rightsquigarrow",       "⇝", Rel),
+    ("rightthreearrows",      "⇶", Rel),
+    ("rightthreetimes",       "⋌", Bin),
+    ("rightturn",             "↻", Rel),
+    ("rightwhitearrow",       "⇨", Rel),
+    ("risingdotseq",          "≓", Rel),
+    ("rmoustache",            "⎱", Ord),
+    ("rparengtr",             "⦔", Close),
+    ("rppolint",              "⨒", Op),
+    ("rrbracket",             "⟧", Close),
+    ("rrparenthesis",         "⦈", Close),
+    ("rtimes",                "⋊", Bin),
+    ("rvzigzag",              "⧙", Close),
+    ("sagittarius",           "♐", Ord),
+    ("sansLmirrored",         "⅃", Ord),
+    ("sansLturned",           "⅂", Ord),
+    ("saturn",                "♄", Ord),
+    ("scorpio",               "♏", Ord),
+    ("scpolint",              "⨓", Op),
+    ("scurel",                "⊱", Rel),
+    ("searrow",               "↘", Rel),
+    ("second",                "″", Ord),
+    ("setminus",              "⧵", Bin),
+    ("sharp",                 "♯", Ord),
+    ("shortdowntack",         "⫟", Rel),
+    ("shortlefttack",         "⫞", Ord),
+    ("shortuptack",           "⫠", Rel),
+    ("sigma",                 "σ", Alpha),
+    ("sim",                   "∼", Rel),
+    ("simeq",                 "≃", Rel),
+    ("slash",                 "∕", Bin),
+    ("smallsetminus",         "∖", Bin),
+    ("smalltriangledown",     "▿", Bin),
+    ("smalltriangleleft",     "◃", Bin),
+    ("smalltriangleright",    "▹", Bin),
+    ("smblksquare",           "▪", Ord),
+    ("smile",                 "⌣", Rel),
+    ("smiley",                "☺", Ord),
+    ("smwhtsquare",           "▫", Ord),
+    ("spadesuit",             "♠", Ord),
+    ("spddot",                "¨", Ord),
+    ("sphat",                 "^", Ord),
+    ("sphericalangle",        "∢", Ord),
+    ("sptilde",               "~", Ord),
+    ("sqcap",                 "⊓", Bin),
+    ("sqcup",                 "⊔", Bin),
+    ("sqint",                 "⨖", Op),
+    ("sqsubset",              "⊏", Rel),
+    ("sqsubseteq",            "⊑", Rel),
+    ("sqsupset",              "⊐", Rel),
+    ("sqsupseteq",            "⊒", Rel),
+    ("square",                "◻", Ord),
+    ("sslash",                "⫽", Bin),
+    ("star",                  "⋆", Bin),
+    ("stareq",                "≛", Rel),
+    ("strictfi",              "⥼", Rel),
+    ("strictif",              "⥽", Rel),
+    ("subset",                "⊂", Rel),
+    ("subseteq",              "⊆", Rel),
+    ("subseteqq",             "⫅", Rel),
+    ("subsetneq",             "⊊", Rel),
+    ("subsetneqq",            "⫋", Rel),
+    ("succ",                  "≻", Rel),
+    ("succapprox",            "⪸", Rel),
+    ("succcurlyeq",           "≽", Rel),
+    ("succeq",                "⪰", Rel),
+    ("succeqq",               "⪴", Rel),
+    ("succnapprox",           "⪺", Rel),
+    ("succneqq",              "⪶", Rel),
+    ("succnsim",              "⋩", Rel),
+    ("succsim",               "≿", Rel),
+    ("sum",                   "∑", Op),
+    ("sumint",                "⨋", Op),
+    ("sun",                   "☼", Ord),
+    ("supset",                "⊃", Rel),
+    ("supseteq",              "⊇", Rel),
+    ("supseteqq",             "⫆", Rel),
+    ("supsetneq",             "⊋", Rel),
+    ("supsetneqq",            "⫌", Rel),
+    ("surd",                  "√", Ord),
+    ("swarrow",               "↙", Rel),
+    ("talloblong",            "⫾", Bin),
+    ("tau",                   "τ", Alpha),
+    ("taurus",                "♉", Ord),
+    ("therefore",             "∴", Rel),
+    ("theta",                 "θ", Alpha),
+    ("third",                 "‴", Ord),
+    ("times",                 "×", Bin),
+    ("to",                    "→", Rel),
+    ("top",                   "⊤", Ord),
+    ("topfork",               "⫚", Rel),
+    ("triangleleft",          "◃", Bin),
+    ("trianglelefteq",        "⊴", Rel),
+    ("triangleq",             "≜", Rel),
+    ("triangleright",         "▹", Bin),
+    ("trianglerighteq",       "⊵", Rel),
+    ("turnediota",            "℩", Ord),
+    ("twoheaddownarrow",      "↡", Rel),
+    ("twoheadleftarrow",      "↞", Rel),
+    ("twoheadrightarrow",     "↠", Rel),
+    ("twoheaduparrow",        "↟", Rel),
+    ("twonotes",              "♫", Ord),
+    ("ulcorner",              "⌜", Open),
+    ("unicodecdots",          "⋯", Ord),
+    ("unicodeellipsis",       "…", Ord),
+    ("upand",                 "⅋", Bin),
+    ("uparrow",               "↑", Rel),
+    ("updasharrow",           "⇡", Rel),
+    ("updownarrow",           "↕", Rel),
+    ("updownarrowbar",        "↨", Rel),
+    ("updownarrows",          "⇅", Rel),
+    ("updownharpoons",        "⥮", Rel),
+    ("upharpoonleft",         "↿", Rel),
+    ("upharpoonright",        "↾", Rel),
+    ("upint",                 "⨛", Op),
+    ("uplus",                 "⊎", Bin),
+    ("upsilon",               "υ", Alpha),
+    ("upuparrows",            "⇈", Rel),
+    ("upupharpoons",          "⥣", Rel),
+    ("upwhitearrow",          "⇧", Rel),
+    ("uranus",                "♅", Ord),
+    ("urcorner",              "⌝", Close),
+    ("vBar",                  "⫨", Rel),
+    ("vDash",                 "⊨", Rel),
+    ("vDdash",                "⫢", Rel),
+    ("varDelta",              "𝛥", Alpha),
+    ("varEarth",              "♁", Ord),
+    ("varGamma",              "𝛤", Alpha),
+    ("varLambda",             "𝛬", Alpha),
+    ("varOmega",              "𝛺", Alpha),
+    ("varPhi",                "𝛷", Alpha),
+    ("varPi",                 "𝛱", Alpha),
+    ("varPsi",                "𝛹", Alpha),
+    ("varSigma",              "𝛴", Alpha),
+    ("varTheta",              "𝛩", Alpha),
+    ("varUpsilon",            "𝛶", Alpha),
+    ("varXi",                 "𝛯", Alpha),
+    ("varclubsuit",           "♧", Ord),
+    ("vardiamondsuit",        "♦", Ord),
+    ("varepsilon",            "ε", Alpha),
+    ("varheartsuit",          "♥", Ord),
+    ("varkappa",              "ϰ", Alpha),
+    ("varnothing",            "∅", Ord),
+    ("varointclockwise",      "∲", Op),
+    ("varphi",                "φ", Alpha),
+    ("varpi",                 "ϖ", Alpha),
+    ("varprod",               "⨉", Op),
+    ("varrho",                "ϱ", Alpha),
+    ("varsigma",              "ς", Alpha),
+    ("varspadesuit",          "♤", Ord),
+    ("varstar",               "✶", Ord),
+    ("vartheta",              "ϑ", Alpha),
+    ("vartriangle",           "▵", Rel),
+    ("vartriangleleft",       "⊲", Rel),
+    ("vartriangleright",      "⊳", Rel),
+    ("vdash",                 "⊢", Rel),
+    ("vdots",                 "⋮", Punct),
+    ("vee",                   "∨", Bin),
+    ("veebar",                "⊻", Bin),
+    ("veedot",                "⟇", Bin),
+    ("veeeq",                 "≚", Rel),
+    ("vert",                  "|", Fence),
+    ("virgo",                 "♍", Ord),
+    ("wasylozenge",           "⌑", Ord),
+    ("wasytherefore",         "∴", Rel),
+    ("wedge",                 "∧", Bin),
+    ("wedgedot",              "⟑", Bin),
+    ("wedgeq",                "≙", Rel),
+    ("wp",                    "℘", Alpha),
+    ("wr",                    "≀", Bin),
+    ("xbsol",                 "⧹", Op),
+    ("xi",                    "ξ", Alpha),
+    ("xsol",                  "⧸", Op),
+    ("yen",                   "¥", Ord),
+    ("zcmp",                  "⨟", Op),
+    ("zeta",                  "ζ", Alpha),
+    ("zpipe",                 "⨠", Op),
+    ("zproject",              "⨡", Op),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{lookup, Class, SYMBOLS};
+
+    #[test]
+    fn symbol_test() {
+        assert!(SYMBOLS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(SYMBOLS.iter().all(|(_, s, _)| s.chars().count() == 1));
+
+        let problems = [
+            ("nleq", Some(("≰", Class::Rel))),
+            ("varnothing", Some(("∅", Class::Ord))),
+            ("circledS", Some(("Ⓢ", Class::Ord))),
+            ("llbracket", Some(("⟦", Class::Open))),
+            ("rBrack", Some(("⟧", Class::Close))),
+            ("iiiint", Some(("⨌", Class::Op))),
+            ("bigstar", Some(("★", Class::Ord))),
+            ("mbfA", Some(("𝐀", Class::Alpha))),
+            ("mith", Some(("ℎ", Class::Alpha))),
+            ("Bbbzero", Some(("𝟘", Class::Ord))),
+            ("mupvarepsilon", Some(("ε", Class::Alpha))),
+            ("unknown", None),
+        ];
+
+        for (name, answer) in problems.iter() {
+            assert_eq!(lookup(name), *answer);
+        }
+    }
+}
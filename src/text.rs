@@ -0,0 +1,208 @@
+//! Text
+//!
+//! テキストモードの記号やアクセントを Unicode の文字に変換する.
+//!
+
+/// `\ss` や `\textdegree` のようなテキストモードの記号を返す.
+pub(crate) fn text_symbol(command: &str) -> Option<&'static str> {
+    let symbol = match command {
+        "ss"  => "ß",
+        "SS"  => "SS",
+        "o"   => "ø",
+        "O"   => "Ø",
+        "ae"  => "æ",
+        "AE"  => "Æ",
+        "oe"  => "œ",
+        "OE"  => "Œ",
+        "aa"  => "å",
+        "AA"  => "Å",
+        "l"   => "ł",
+        "L"   => "Ł",
+        "i"   => "ı",
+        "j"   => "ȷ",
+        "dh"  => "ð",
+        "DH"  => "Ð",
+        "th"  => "þ",
+        "TH"  => "Þ",
+        "ng"  => "ŋ",
+        "NG"  => "Ŋ",
+        "dag"  => "†",
+        "ddag" => "‡",
+        "S"    => "§",
+        "P"    => "¶",
+        "copyright" => "©",
+        "pounds"    => "£",
+        "dots"      => "…",
+        "ldots"     => "…",
+        "textellipsis"      => "…",
+        "textdegree"        => "°",
+        "textcelsius"       => "℃",
+        "textperthousand"   => "‰",
+        "textpm"            => "±",
+        "texttimes"         => "×",
+        "textdiv"           => "÷",
+        "textmu"            => "µ",
+        "textendash"        => "–",
+        "textemdash"        => "—",
+        "textquoteleft"     => "‘",
+        "textquoteright"    => "’",
+        "textquotedblleft"  => "“",
+        "textquotedblright" => "”",
+        "guillemotleft"     => "«",
+        "guillemotright"    => "»",
+        "textexclamdown"    => "¡",
+        "textquestiondown"  => "¿",
+        "textbackslash"     => "\\",
+        "textasciitilde"    => "~",
+        "textasciicircum"   => "^",
+        "textunderscore"    => "_",
+        "textbar"           => "|",
+        "textless"          => "<",
+        "textgreater"       => ">",
+        "textbullet"        => "•",
+        "textregistered"    => "®",
+        "texttrademark"     => "™",
+        "texteuro"          => "€",
+        "euro"              => "€",
+        _ => { return None; },
+    };
+    Some(symbol)
+}
+
+/// `\"o` や `\c{c}` のようなアクセントのコマンドに対応する結合文字を返す.
+pub(crate) fn text_accent(command: &str) -> Option<char> {
+    let accent = match command {
+        "`"  => '\u{300}',
+        "'"  => '\u{301}',
+        "^"  => '\u{302}',
+        "~"  => '\u{303}',
+        "="  => '\u{304}',
+        "u"  => '\u{306}',
+        "."  => '\u{307}',
+        "\"" => '\u{308}',
+        "r"  => '\u{30a}',
+        "H"  => '\u{30b}',
+        "v"  => '\u{30c}',
+        "d"  => '\u{323}',
+        "c"  => '\u{327}',
+        "k"  => '\u{328}',
+        "b"  => '\u{331}',
+        "t"  => '\u{361}',
+        _ => { return None; },
+    };
+    Some(accent)
+}
+
+/// 文字 `base` に結合文字 `accent` を付ける.
+///
+/// 合成済みの文字があればそれを, なければ `base` と `accent` を並べたものを返す.
+pub(crate) fn compose(base: char, accent: char) -> String {
+    match COMPOSITIONS.binary_search_by(|&(b, a, _)| (b, a).cmp(&(base, accent))) {
+        Ok(i) => COMPOSITIONS[i].2.to_string(),
+        Err(_) => format!("{}{}", base, accent),
+    }
+}
+
+/// 基底の文字と結合文字から合成済みの文字への表 (基底の文字, 結合文字の順に整列済み)
+static COMPOSITIONS: &[(char, char, char)] = &[
+    ('A', '\u{300}', 'À'), ('A', '\u{301}', 'Á'), ('A', '\u{302}', 'Â'), ('A', '\u{303}', 'Ã'),
+    ('A', '\u{304}', 'Ā'), ('A', '\u{306}', 'Ă'), ('A', '\u{307}', 'Ȧ'), ('A', '\u{308}', 'Ä'),
+    ('A', '\u{30a}', 'Å'), ('A', '\u{30c}', 'Ǎ'), ('A', '\u{323}', 'Ạ'), ('A', '\u{328}', 'Ą'),
+    ('B', '\u{307}', 'Ḃ'), ('B', '\u{323}', 'Ḅ'), ('B', '\u{331}', 'Ḇ'), ('C', '\u{301}', 'Ć'),
+    ('C', '\u{302}', 'Ĉ'), ('C', '\u{307}', 'Ċ'), ('C', '\u{30c}', 'Č'), ('C', '\u{327}', 'Ç'),
+    ('D', '\u{307}', 'Ḋ'), ('D', '\u{30c}', 'Ď'), ('D', '\u{323}', 'Ḍ'), ('D', '\u{327}', 'Ḑ'),
+    ('D', '\u{331}', 'Ḏ'), ('E', '\u{300}', 'È'), ('E', '\u{301}', 'É'), ('E', '\u{302}', 'Ê'),
+    ('E', '\u{303}', 'Ẽ'), ('E', '\u{304}', 'Ē'), ('E', '\u{306}', 'Ĕ'), ('E', '\u{307}', 'Ė'),
+    ('E', '\u{308}', 'Ë'), ('E', '\u{30c}', 'Ě'), ('E', '\u{323}', 'Ẹ'), ('E', '\u{327}', 'Ȩ'),
+    ('E', '\u{328}', 'Ę'), ('F', '\u{307}', 'Ḟ'), ('G', '\u{301}', 'Ǵ'), ('G', '\u{302}', 'Ĝ'),
+    ('G', '\u{304}', 'Ḡ'), ('G', '\u{306}', 'Ğ'), ('G', '\u{307}', 'Ġ'), ('G', '\u{30c}', 'Ǧ'),
+    ('G', '\u{327}', 'Ģ'), ('H', '\u{302}', 'Ĥ'), ('H', '\u{307}', 'Ḣ'), ('H', '\u{308}', 'Ḧ'),
+    ('H', '\u{30c}', 'Ȟ'), ('H', '\u{323}', 'Ḥ'), ('H', '\u{327}', 'Ḩ'), ('I', '\u{300}', 'Ì'),
+    ('I', '\u{301}', 'Í'), ('I', '\u{302}', 'Î'), ('I', '\u{303}', 'Ĩ'), ('I', '\u{304}', 'Ī'),
+    ('I', '\u{306}', 'Ĭ'), ('I', '\u{307}', 'İ'), ('I', '\u{308}', 'Ï'), ('I', '\u{30c}', 'Ǐ'),
+    ('I', '\u{323}', 'Ị'), ('I', '\u{328}', 'Į'), ('J', '\u{302}', 'Ĵ'), ('K', '\u{301}', 'Ḱ'),
+    ('K', '\u{30c}', 'Ǩ'), ('K', '\u{323}', 'Ḳ'), ('K', '\u{327}', 'Ķ'), ('K', '\u{331}', 'Ḵ'),
+    ('L', '\u{301}', 'Ĺ'), ('L', '\u{30c}', 'Ľ'), ('L', '\u{323}', 'Ḷ'), ('L', '\u{327}', 'Ļ'),
+    ('L', '\u{331}', 'Ḻ'), ('M', '\u{301}', 'Ḿ'), ('M', '\u{307}', 'Ṁ'), ('M', '\u{323}', 'Ṃ'),
+    ('N', '\u{300}', 'Ǹ'), ('N', '\u{301}', 'Ń'), ('N', '\u{303}', 'Ñ'), ('N', '\u{307}', 'Ṅ'),
+    ('N', '\u{30c}', 'Ň'), ('N', '\u{323}', 'Ṇ'), ('N', '\u{327}', 'Ņ'), ('N', '\u{331}', 'Ṉ'),
+    ('O', '\u{300}', 'Ò'), ('O', '\u{301}', 'Ó'), ('O', '\u{302}', 'Ô'), ('O', '\u{303}', 'Õ'),
+    ('O', '\u{304}', 'Ō'), ('O', '\u{306}', 'Ŏ'), ('O', '\u{307}', 'Ȯ'), ('O', '\u{308}', 'Ö'),
+    ('O', '\u{30b}', 'Ő'), ('O', '\u{30c}', 'Ǒ'), ('O', '\u{323}', 'Ọ'), ('O', '\u{328}', 'Ǫ'),
+    ('P', '\u{301}', 'Ṕ'), ('P', '\u{307}', 'Ṗ'), ('R', '\u{301}', 'Ŕ'), ('R', '\u{307}', 'Ṙ'),
+    ('R', '\u{30c}', 'Ř'), ('R', '\u{323}', 'Ṛ'), ('R', '\u{327}', 'Ŗ'), ('R', '\u{331}', 'Ṟ'),
+    ('S', '\u{301}', 'Ś'), ('S', '\u{302}', 'Ŝ'), ('S', '\u{307}', 'Ṡ'), ('S', '\u{30c}', 'Š'),
+    ('S', '\u{323}', 'Ṣ'), ('S', '\u{327}', 'Ş'), ('T', '\u{307}', 'Ṫ'), ('T', '\u{30c}', 'Ť'),
+    ('T', '\u{323}', 'Ṭ'), ('T', '\u{327}', 'Ţ'), ('T', '\u{331}', 'Ṯ'), ('U', '\u{300}', 'Ù'),
+    ('U', '\u{301}', 'Ú'), ('U', '\u{302}', 'Û'), ('U', '\u{303}', 'Ũ'), ('U', '\u{304}', 'Ū'),
+    ('U', '\u{306}', 'Ŭ'), ('U', '\u{308}', 'Ü'), ('U', '\u{30a}', 'Ů'), ('U', '\u{30b}', 'Ű'),
+    ('U', '\u{30c}', 'Ǔ'), ('U', '\u{323}', 'Ụ'), ('U', '\u{328}', 'Ų'), ('V', '\u{303}', 'Ṽ'),
+    ('V', '\u{323}', 'Ṿ'), ('W', '\u{300}', 'Ẁ'), ('W', '\u{301}', 'Ẃ'), ('W', '\u{302}', 'Ŵ'),
+    ('W', '\u{307}', 'Ẇ'), ('W', '\u{308}', 'Ẅ'), ('W', '\u{323}', 'Ẉ'), ('X', '\u{307}', 'Ẋ'),
+    ('X', '\u{308}', 'Ẍ'), ('Y', '\u{300}', 'Ỳ'), ('Y', '\u{301}', 'Ý'), ('Y', '\u{302}', 'Ŷ'),
+    ('Y', '\u{303}', 'Ỹ'), ('Y', '\u{304}', 'Ȳ'), ('Y', '\u{307}', 'Ẏ'), ('Y', '\u{308}', 'Ÿ'),
+    ('Y', '\u{323}', 'Ỵ'), ('Z', '\u{301}', 'Ź'), ('Z', '\u{302}', 'Ẑ'), ('Z', '\u{307}', 'Ż'),
+    ('Z', '\u{30c}', 'Ž'), ('Z', '\u{323}', 'Ẓ'), ('Z', '\u{331}', 'Ẕ'), ('a', '\u{300}', 'à'),
+    ('a', '\u{301}', 'á'), ('a', '\u{302}', 'â'), ('a', '\u{303}', 'ã'), ('a', '\u{304}', 'ā'),
+    ('a', '\u{306}', 'ă'), ('a', '\u{307}', 'ȧ'), ('a', '\u{308}', 'ä'), ('a', '\u{30a}', 'å'),
+    ('a', '\u{30c}', 'ǎ'), ('a', '\u{323}', 'ạ'), ('a', '\u{328}', 'ą'), ('b', '\u{307}', 'ḃ'),
+    ('b', '\u{323}', 'ḅ'), ('b', '\u{331}', 'ḇ'), ('c', '\u{301}', 'ć'), ('c', '\u{302}', 'ĉ'),
+    ('c', '\u{307}', 'ċ'), ('c', '\u{30c}', 'č'), ('c', '\u{327}', 'ç'), ('d', '\u{307}', 'ḋ'),
+    ('d', '\u{30c}', 'ď'), ('d', '\u{323}', 'ḍ'), ('d', '\u{327}', 'ḑ'), ('d', '\u{331}', 'ḏ'),
+    ('e', '\u{300}', 'è'), ('e', '\u{301}', 'é'), ('e', '\u{302}', 'ê'), ('e', '\u{303}', 'ẽ'),
+    ('e', '\u{304}', 'ē'), ('e', '\u{306}', 'ĕ'), ('e', '\u{307}', 'ė'), ('e', '\u{308}', 'ë'),
+    ('e', '\u{30c}', 'ě'), ('e', '\u{323}', 'ẹ'), ('e', '\u{327}', 'ȩ'), ('e', '\u{328}', 'ę'),
+    ('f', '\u{307}', 'ḟ'), ('g', '\u{301}', 'ǵ'), ('g', '\u{302}', 'ĝ'), ('g', '\u{304}', 'ḡ'),
+    ('g', '\u{306}', 'ğ'), ('g', '\u{307}', 'ġ'), ('g', '\u{30c}', 'ǧ'), ('g', '\u{327}', 'ģ'),
+    ('h', '\u{302}', 'ĥ'), ('h', '\u{307}', 'ḣ'), ('h', '\u{308}', 'ḧ'), ('h', '\u{30c}', 'ȟ'),
+    ('h', '\u{323}', 'ḥ'), ('h', '\u{327}', 'ḩ'), ('h', '\u{331}', 'ẖ'), ('i', '\u{300}', 'ì'),
+    ('i', '\u{301}', 'í'), ('i', '\u{302}', 'î'), ('i', '\u{303}', 'ĩ'), ('i', '\u{304}', 'ī'),
+    ('i', '\u{306}', 'ĭ'), ('i', '\u{308}', 'ï'), ('i', '\u{30c}', 'ǐ'), ('i', '\u{323}', 'ị'),
+    ('i', '\u{328}', 'į'), ('j', '\u{302}', 'ĵ'), ('j', '\u{30c}', 'ǰ'), ('k', '\u{301}', 'ḱ'),
+    ('k', '\u{30c}', 'ǩ'), ('k', '\u{323}', 'ḳ'), ('k', '\u{327}', 'ķ'), ('k', '\u{331}', 'ḵ'),
+    ('l', '\u{301}', 'ĺ'), ('l', '\u{30c}', 'ľ'), ('l', '\u{323}', 'ḷ'), ('l', '\u{327}', 'ļ'),
+    ('l', '\u{331}', 'ḻ'), ('m', '\u{301}', 'ḿ'), ('m', '\u{307}', 'ṁ'), ('m', '\u{323}', 'ṃ'),
+    ('n', '\u{300}', 'ǹ'), ('n', '\u{301}', 'ń'), ('n', '\u{303}', 'ñ'), ('n', '\u{307}', 'ṅ'),
+    ('n', '\u{30c}', 'ň'), ('n', '\u{323}', 'ṇ'), ('n', '\u{327}', 'ņ'), ('n', '\u{331}', 'ṉ'),
+    ('o', '\u{300}', 'ò'), ('o', '\u{301}', 'ó'), ('o', '\u{302}', 'ô'), ('o', '\u{303}', 'õ'),
+    ('o', '\u{304}', 'ō'), ('o', '\u{306}', 'ŏ'), ('o', '\u{307}', 'ȯ'), ('o', '\u{308}', 'ö'),
+    ('o', '\u{30b}', 'ő'), ('o', '\u{30c}', 'ǒ'), ('o', '\u{323}', 'ọ'), ('o', '\u{328}', 'ǫ'),
+    ('p', '\u{301}', 'ṕ'), ('p', '\u{307}', 'ṗ'), ('r', '\u{301}', 'ŕ'), ('r', '\u{307}', 'ṙ'),
+    ('r', '\u{30c}', 'ř'), ('r', '\u{323}', 'ṛ'), ('r', '\u{327}', 'ŗ'), ('r', '\u{331}', 'ṟ'),
+    ('s', '\u{301}', 'ś'), ('s', '\u{302}', 'ŝ'), ('s', '\u{307}', 'ṡ'), ('s', '\u{30c}', 'š'),
+    ('s', '\u{323}', 'ṣ'), ('s', '\u{327}', 'ş'), ('t', '\u{307}', 'ṫ'), ('t', '\u{308}', 'ẗ'),
+    ('t', '\u{30c}', 'ť'), ('t', '\u{323}', 'ṭ'), ('t', '\u{327}', 'ţ'), ('t', '\u{331}', 'ṯ'),
+    ('u', '\u{300}', 'ù'), ('u', '\u{301}', 'ú'), ('u', '\u{302}', 'û'), ('u', '\u{303}', 'ũ'),
+    ('u', '\u{304}', 'ū'), ('u', '\u{306}', 'ŭ'), ('u', '\u{308}', 'ü'), ('u', '\u{30a}', 'ů'),
+    ('u', '\u{30b}', 'ű'), ('u', '\u{30c}', 'ǔ'), ('u', '\u{323}', 'ụ'), ('u', '\u{328}', 'ų'),
+    ('v', '\u{303}', 'ṽ'), ('v', '\u{323}', 'ṿ'), ('w', '\u{300}', 'ẁ'), ('w', '\u{301}', 'ẃ'),
+    ('w', '\u{302}', 'ŵ'), ('w', '\u{307}', 'ẇ'), ('w', '\u{308}', 'ẅ'), ('w', '\u{30a}', 'ẘ'),
+    ('w', '\u{323}', 'ẉ'), ('x', '\u{307}', 'ẋ'), ('x', '\u{308}', 'ẍ'), ('y', '\u{300}', 'ỳ'),
+    ('y', '\u{301}', 'ý'), ('y', '\u{302}', 'ŷ'), ('y', '\u{303}', 'ỹ'), ('y', '\u{304}', 'ȳ'),
+    ('y', '\u{307}', 'ẏ'), ('y', '\u{308}', 'ÿ'), ('y', '\u{30a}', 'ẙ'), ('y', '\u{323}', 'ỵ'),
+    ('z', '\u{301}', 'ź'), ('z', '\u{302}', 'ẑ'), ('z', '\u{307}', 'ż'), ('z', '\u{30c}', 'ž'),
+    ('z', '\u{323}', 'ẓ'), ('z', '\u{331}', 'ẕ'), ('ı', '\u{300}', 'ì'), ('ı', '\u{301}', 'í'),
+    ('ı', '\u{302}', 'î'), ('ı', '\u{304}', 'ī'), ('ı', '\u{306}', 'ĭ'), ('ı', '\u{308}', 'ï'),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{compose, COMPOSITIONS};
+
+    #[test]
+    fn text_test() {
+        assert!(COMPOSITIONS.windows(2).all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+
+        let problems = [
+            ('o', '\u{308}', "ö"),
+            ('e', '\u{301}', "é"),
+            ('c', '\u{327}', "ç"),
+            ('ı', '\u{301}', "í"),
+            ('q', '\u{308}', "q\u{308}"),
+        ];
+
+        for (base, accent, answer) in problems.iter() {
+            assert_eq!(&compose(*base, *accent), answer);
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use super::attribute::{Variant, Accent};
-use crate::DisplayStyle;
+use super::attribute::{Variant, Accent, Stretchy, LineThickness, MathStyle, MathClass, Length};
+use super::symbol::{self, Class};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Token {
@@ -15,10 +15,14 @@ pub enum Token {
     Paren(&'static str),
     LBrace,
     RBrace,
-    Frac,
+    Frac(Option<MathStyle>),
+    CFrac,
+    GenFrac,
+    SFrac,
+    InfixFrac(&'static str, &'static str, LineThickness),
     Underscore,
     Circumflex,
-    Binom(Option<DisplayStyle>),
+    Binom(Option<MathStyle>),
     Overset,
     Underset,
     Overbrace(char),
@@ -26,48 +30,145 @@ pub enum Token {
     Sqrt,
     Integral(char),
     Lim(&'static str),
-    Space(f32),
+    Space(Length),
+    Kern,
+    CharCode,
     Style(Variant),
-    Big(&'static str),
-    Over(char, Accent),
-    Under(char, Accent),
+    BoldSymbol,
+    Font(Variant),
+    MathStyle(MathStyle),
+    Big(&'static str, Option<&'static str>),
+    Over(char, Accent, Stretchy),
+    Under(char, Accent, Stretchy),
     Operator(char),
+    Dots,
+    HDotsFor,
     BigOp(char),
     Letter(char, Variant),
+    Symbol(char),
     Number(String),
     Function(&'static str),
     OperatorName,
+    Bmod,
+    Pmod,
+    Pod,
+    Mod,
+    MathClass(MathClass),
+    Limits(bool),
     Slashed,
-    Text,
+    Not,
+    Enclose(&'static str),
+    GenEnclose,
+    CancelTo,
+    Fbox,
+    BBox,
+    Phantom,
+    HPhantom,
+    VPhantom,
+    Smash,
+    MathStrut,
+    Strut,
+    Lap(Option<&'static str>),
+    Href,
+    Url,
+    Html(&'static str),
+    HtmlData,
+    Color,
+    TextColor,
+    ColorBox,
+    FColorBox,
+    Text(Option<Variant>),
+    Emph,
     Command(String),
 }
 
 impl Token {
     pub(crate) fn acts_on_a_digit(&self) -> bool {
-        match self {
-            Token::Sqrt | Token::Frac | Token::Binom(_) | Token::Style(_) => true,
-            _ => false,
+        matches!(self, Token::Sqrt | Token::Frac(_) | Token::CFrac | Token::SFrac | Token::Binom(_) | Token::Style(_) | Token::BoldSymbol)
+    }
+
+    /// 文字を演算子辞書に従って分類する.
+    ///
+    /// `\sum` や `\alpha` のようにコマンドでも入力できる文字は, コマンドと同じトークンになる.
+    /// それ以外の演算子は `<mo>`, 文字は `<mi>`, 数字は `<mn>`, その他は `<mtext>` になる.
+    pub(crate) fn from_char(c: char) -> Token {
+        match c {
+            // 大型演算子
+            '∑' | '∏' | '∐' | '⋀' | '⋁' | '⋂' | '⋃' | '⨀' | '⨁' | '⨂' | '⨃' | '⨄' | '⨅' | '⨆' => Token::BigOp(c),
+            // 積分
+            '\u{222b}'..='\u{2233}' | '\u{2a0b}'..='\u{2a1c}' => Token::Integral(c),
+            // 括弧
+            '⟨' | '〈' => Token::Paren("⟨"),
+            '⟩' | '〉' => Token::Paren("⟩"),
+            '⌈' => Token::Paren("⌈"),
+            '⌉' => Token::Paren("⌉"),
+            '⌊' => Token::Paren("⌊"),
+            '⌋' => Token::Paren("⌋"),
+            '⟦' => Token::Paren("⟦"),
+            '⟧' => Token::Paren("⟧"),
+            '⦗' => Token::Paren("⦗"),
+            '⦘' => Token::Paren("⦘"),
+            '⌜' => Token::Paren("⌜"),
+            '⌝' => Token::Paren("⌝"),
+            '⌞' => Token::Paren("⌞"),
+            '⌟' => Token::Paren("⌟"),
+            '⎰' => Token::Paren("⎰"),
+            '⎱' => Token::Paren("⎱"),
+            '‖' => Token::Paren("‖"),
+            // ギリシャ文字 (大文字は直立体)
+            '\u{391}'..='\u{3a9}' => Token::Letter(c, Variant::Normal),
+            '\u{3b1}'..='\u{3c9}' | 'ϐ' | 'ϑ' | 'ϕ' | 'ϖ' | 'ϰ' | 'ϱ' | 'ϵ' => Token::Letter(c, Variant::Italic),
+            c if is_ordinary_symbol(c) => Token::Letter(c, Variant::Normal),
+            c if is_operator(c) => Token::Operator(c),
+            c if c.is_numeric() => Token::Number(c.to_string()),
+            c if c.is_ascii_alphabetic() || ('\u{2100}'..='\u{214f}').contains(&c) 
+                || ('\u{1d400}'..='\u{1d7ff}').contains(&c) => Token::Letter(c, Variant::Italic),
+            c if c.is_alphabetic() => Token::Letter(c, Variant::Normal),
+            c => Token::Symbol(c),
         }
     }
 
     pub fn from_command(command: &str) -> Token {
         match command {
             "mathrm"  => Token::Style(Variant::Normal),
-            "textit"  => Token::Style(Variant::Italic),
             "mathit"  => Token::Style(Variant::Italic),
-            "textbf"  => Token::Style(Variant::Bold),
             "mathbf"  => Token::Style(Variant::Bold),
-            "bm"      => Token::Style(Variant::BoldItalic),
-            "symbf"   => Token::Style(Variant::BoldItalic),
+            "bm"      => Token::BoldSymbol,
+            "symbf"   => Token::BoldSymbol,
             "mathbb"  => Token::Style(Variant::DoubleStruck),
             "mathfrak" => Token::Style(Variant::Fraktur),
             "mathscr" => Token::Style(Variant::Script),
             "mathsf"  => Token::Style(Variant::SansSerif),
-            "texttt"  => Token::Style(Variant::Monospace),
-            "boldsymbol" => Token::Style(Variant::BoldItalic),
-            "text"   => Token::Text,
+            "boldsymbol" => Token::BoldSymbol,
+            "rm"      => Token::Font(Variant::Normal),
+            "bf"      => Token::Font(Variant::Bold),
+            "it"      => Token::Font(Variant::Italic),
+            "sf"      => Token::Font(Variant::SansSerif),
+            "tt"      => Token::Font(Variant::Monospace),
+            "cal"     => Token::Font(Variant::Script),
+            "Bbb"     => Token::Font(Variant::DoubleStruck),
+            "frak"    => Token::Font(Variant::Fraktur),
+            "text"   => Token::Text(None),
+            "mbox"   => Token::Text(None),
+            "textrm" => Token::Text(Some(Variant::Normal)),
+            "textbf" => Token::Text(Some(Variant::Bold)),
+            "textit" => Token::Text(Some(Variant::Italic)),
+            "textsf" => Token::Text(Some(Variant::SansSerif)),
+            "texttt" => Token::Text(Some(Variant::Monospace)),
+            "emph"   => Token::Emph,
             "sqrt"   => Token::Sqrt,
-            "frac"   => Token::Frac,
+            "frac"   => Token::Frac(None),
+            "tfrac"  => Token::Frac(Some(MathStyle::Text)),
+            "dfrac"  => Token::Frac(Some(MathStyle::Display)),
+            "cfrac"  => Token::CFrac,
+            "genfrac" => Token::GenFrac,
+            "sfrac"   => Token::SFrac,
+            "nicefrac" => Token::SFrac,
+            "over"   => Token::InfixFrac("", "", LineThickness::Medium),
+            "atop"   => Token::InfixFrac("", "", LineThickness::Length(0)),
+            "choose" => Token::InfixFrac("(", ")", LineThickness::Length(0)),
+            "brack"  => Token::InfixFrac("[", "]", LineThickness::Length(0)),
+            "brace"  => Token::InfixFrac("{", "}", LineThickness::Length(0)),
             "left"   => Token::Left,
             "right"  => Token::Right,
             "middle"  => Token::Middle,
@@ -75,8 +176,12 @@ impl Token {
             "end"    => Token::End,
             "\\"     => Token::NewLine,
             "binom"  => Token::Binom(None),
-            "tbinom"  => Token::Binom(Some(DisplayStyle::Inline)),
-            "dbinom"  => Token::Binom(Some(DisplayStyle::Block)),
+            "tbinom"  => Token::Binom(Some(MathStyle::Text)),
+            "dbinom"  => Token::Binom(Some(MathStyle::Display)),
+            "displaystyle"      => Token::MathStyle(MathStyle::Display),
+            "textstyle"         => Token::MathStyle(MathStyle::Text),
+            "scriptstyle"       => Token::MathStyle(MathStyle::Script),
+            "scriptscriptstyle" => Token::MathStyle(MathStyle::ScriptScript),
             "overset"  => Token::Overset,
             "underset" => Token::Underset,
             "overbrace"  => Token::Overbrace('\u{23de}'),
@@ -85,15 +190,33 @@ impl Token {
             "underparen" => Token::Underbrace('\u{23dd}'),
             "overbracket"  => Token::Overbrace('\u{23b4}'),
             "underbracket" => Token::Underbrace('\u{23b5}'),
-            "!"     => Token::Space(-3./18.),
-            ","     => Token::Space(3./18.),
-            ":"     => Token::Space(4./18.),
-            ";"     => Token::Space(5./18.),
-            " "     => Token::Space(1.),
-            "quad"  => Token::Space(1.),
-            "qquad" => Token::Space(2.),
-            "langle" => Token::Paren("&lang;"),
-            "rangle" => Token::Paren("&rang;"),
+            "!"     => Token::Space(Length::mu(-3.)),
+            ","     => Token::Space(Length::mu(3.)),
+            ":"     => Token::Space(Length::mu(4.)),
+            ">"     => Token::Space(Length::mu(4.)),
+            ";"     => Token::Space(Length::mu(5.)),
+            " "     => Token::Space(Length::em(1.)),
+            "quad"  => Token::Space(Length::em(1.)),
+            "qquad" => Token::Space(Length::em(2.)),
+            "thinspace"     => Token::Space(Length::mu(3.)),
+            "medspace"      => Token::Space(Length::mu(4.)),
+            "thickspace"    => Token::Space(Length::mu(5.)),
+            "negthinspace"  => Token::Space(Length::mu(-3.)),
+            "negmedspace"   => Token::Space(Length::mu(-4.)),
+            "negthickspace" => Token::Space(Length::mu(-5.)),
+            "enspace" => Token::Space(Length::em(0.5)),
+            "enskip"  => Token::Space(Length::em(0.5)),
+            "hspace" => Token::Kern,
+            "mspace" => Token::Kern,
+            "kern"   => Token::Kern,
+            "mkern"  => Token::Kern,
+            "hskip"  => Token::Kern,
+            "mskip"  => Token::Kern,
+            "unicode" => Token::CharCode,
+            "char"    => Token::CharCode,
+            "symbol"  => Token::CharCode,
+            "langle" => Token::Paren("⟨"),
+            "rangle" => Token::Paren("⟩"),
             "{"      => Token::Paren("{"),
             "}"      => Token::Paren("}"),
             "lceil"  => Token::Paren("⌈"),
@@ -104,6 +227,23 @@ impl Token {
             "rgroup" => Token::Paren("⦘"),
             "llbracket" => Token::Paren("⟦"),
             "rrbracket" => Token::Paren("⟧"),
+            "lbrace" => Token::Paren("{"),
+            "rbrace" => Token::Paren("}"),
+            "lbrack" => Token::Paren("["),
+            "rbrack" => Token::Paren("]"),
+            "vert"   => Token::Paren("|"),
+            "lvert"  => Token::Paren("|"),
+            "rvert"  => Token::Paren("|"),
+            "Vert"   => Token::Paren("‖"),
+            "lVert"  => Token::Paren("‖"),
+            "rVert"  => Token::Paren("‖"),
+            "backslash" => Token::Paren("\\"),
+            "ulcorner" => Token::Paren("⌜"),
+            "urcorner" => Token::Paren("⌝"),
+            "llcorner" => Token::Paren("⌞"),
+            "lrcorner" => Token::Paren("⌟"),
+            "lmoustache" => Token::Paren("⎰"),
+            "rmoustache" => Token::Paren("⎱"),
             "lim"    => Token::Lim("lim"),
             "liminf" => Token::Lim("lim inf"),
             "limsup" => Token::Lim("lim sup"),
@@ -115,22 +255,33 @@ impl Token {
             "iint"   => Token::Integral('∬'),
             "iiint"  => Token::Integral('∭'),
             "oint"   => Token::Integral('∮'),
-            "dot"       => Token::Over('\u{02d9}', Accent::True),
-            "ddot"      => Token::Over('¨', Accent::True),
-            "bar"       => Token::Over('¯', Accent::True),
-            "hat"       => Token::Over('^', Accent::True),
-            "check"     => Token::Over('ˇ', Accent::True),
-            "breve"     => Token::Over('˘', Accent::True),
-            "acute"     => Token::Over('´', Accent::True),
-            "grave"     => Token::Over('`', Accent::True),
-            "tilde"     => Token::Over('~', Accent::True),
-            "vec"       => Token::Over('→', Accent::True),
-            "overline"  => Token::Over('_', Accent::True),
-            "underline" => Token::Under('_', Accent::True),
-            "widehat"        => Token::Over('^', Accent::True),
-            "widetilde"      => Token::Over('~', Accent::True),
-            "overrightarrow" => Token::Over('→', Accent::True),
-            "overleftarrow"  => Token::Over('←', Accent::True),
+            "dot"       => Token::Over('\u{02d9}', Accent::True, Stretchy::False),
+            "ddot"      => Token::Over('¨', Accent::True, Stretchy::False),
+            "dddot"     => Token::Over('\u{20db}', Accent::True, Stretchy::False),
+            "ddddot"    => Token::Over('\u{20dc}', Accent::True, Stretchy::False),
+            "mathring"  => Token::Over('˚', Accent::True, Stretchy::False),
+            "bar"       => Token::Over('¯', Accent::True, Stretchy::False),
+            "hat"       => Token::Over('^', Accent::True, Stretchy::False),
+            "check"     => Token::Over('ˇ', Accent::True, Stretchy::False),
+            "breve"     => Token::Over('˘', Accent::True, Stretchy::False),
+            "acute"     => Token::Over('´', Accent::True, Stretchy::False),
+            "grave"     => Token::Over('`', Accent::True, Stretchy::False),
+            "tilde"     => Token::Over('~', Accent::True, Stretchy::False),
+            "vec"       => Token::Over('→', Accent::True, Stretchy::False),
+            "widehat"   => Token::Over('^', Accent::True, Stretchy::True),
+            "widetilde" => Token::Over('~', Accent::True, Stretchy::True),
+            "widecheck" => Token::Over('ˇ', Accent::True, Stretchy::True),
+            "overline"  => Token::Over('‾', Accent::True, Stretchy::True),
+            "underline" => Token::Under('_', Accent::True, Stretchy::True),
+            "overrightarrow"     => Token::Over('→', Accent::True, Stretchy::True),
+            "overleftarrow"      => Token::Over('←', Accent::True, Stretchy::True),
+            "overleftrightarrow" => Token::Over('↔', Accent::True, Stretchy::True),
+            "overleftharpoon"    => Token::Over('↼', Accent::True, Stretchy::True),
+            "overrightharpoon"   => Token::Over('⇀', Accent::True, Stretchy::True),
+            "underrightarrow"     => Token::Under('→', Accent::True, Stretchy::True),
+            "underleftarrow"      => Token::Under('←', Accent::True, Stretchy::True),
+            "underleftrightarrow" => Token::Under('↔', Accent::True, Stretchy::True),
+            "utilde"              => Token::Under('~', Accent::True, Stretchy::True),
             "sum"       => Token::BigOp('∑'),
             "prod"      => Token::BigOp('∏'),
             "coprod"    => Token::BigOp('∐'),
@@ -143,14 +294,22 @@ impl Token {
             "bitotimes" => Token::BigOp('⨂'),
             "bigoplus"  => Token::BigOp('⨁'),
             "biguplus"  => Token::BigOp('⨄'),
-            "bigl"   => Token::Big("1.2em"),
-            "bigr"   => Token::Big("1.2em"),
-            "Bigl"   => Token::Big("1.623em"),
-            "Bigr"   => Token::Big("1.623em"),
-            "biggl"  => Token::Big("2.047em"),
-            "biggr"  => Token::Big("2.047em"),
-            "Biggl"  => Token::Big("2.470em"),
-            "Biggr"  => Token::Big("2.470em"),
+            "big"    => Token::Big("1.2em", None),
+            "bigl"   => Token::Big("1.2em", Some("prefix")),
+            "bigr"   => Token::Big("1.2em", Some("postfix")),
+            "bigm"   => Token::Big("1.2em", Some("infix")),
+            "Big"    => Token::Big("1.623em", None),
+            "Bigl"   => Token::Big("1.623em", Some("prefix")),
+            "Bigr"   => Token::Big("1.623em", Some("postfix")),
+            "Bigm"   => Token::Big("1.623em", Some("infix")),
+            "bigg"   => Token::Big("2.047em", None),
+            "biggl"  => Token::Big("2.047em", Some("prefix")),
+            "biggr"  => Token::Big("2.047em", Some("postfix")),
+            "biggm"  => Token::Big("2.047em", Some("infix")),
+            "Bigg"   => Token::Big("2.470em", None),
+            "Biggl"  => Token::Big("2.470em", Some("prefix")),
+            "Biggr"  => Token::Big("2.470em", Some("postfix")),
+            "Biggm"  => Token::Big("2.470em", Some("infix")),
             // <math xmlns="http://www.w3.org/1998/Math/MathML" display="block">
             //   <semantics>
             //     <mrow>
@@ -198,6 +357,20 @@ impl Token {
             "det"    => Token::Function("det"),
             "wp"     => Token::Function("℘"),
             "operatorname" => Token::OperatorName,
+            "bmod"      => Token::Bmod,
+            "pmod"      => Token::Pmod,
+            "pod"       => Token::Pod,
+            "mod"       => Token::Mod,
+            "mathord"   => Token::MathClass(MathClass::Ord),
+            "mathop"    => Token::MathClass(MathClass::Op),
+            "mathbin"   => Token::MathClass(MathClass::Bin),
+            "mathrel"   => Token::MathClass(MathClass::Rel),
+            "mathopen"  => Token::MathClass(MathClass::Open),
+            "mathclose" => Token::MathClass(MathClass::Close),
+            "mathpunct" => Token::MathClass(MathClass::Punct),
+            "mathinner" => Token::MathClass(MathClass::Inner),
+            "limits"    => Token::Limits(true),
+            "nolimits"  => Token::Limits(false),
             "Alpha"      => Token::Letter('Α', Variant::Normal),
             "alpha"      => Token::Letter('α', Variant::Italic),
             "Beta"       => Token::Letter('Β', Variant::Normal),
@@ -353,6 +526,14 @@ impl Token {
             "vdots"  => Token::Operator('⋮'),
             "ldots"  => Token::Operator('…'),
             "ddots"  => Token::Operator('⋱'),
+            "iddots" => Token::Operator('⋰'),
+            "dots"   => Token::Dots,
+            "dotsb"  => Token::Operator('⋯'),
+            "dotsm"  => Token::Operator('⋯'),
+            "dotsi"  => Token::Operator('⋯'),
+            "dotsc"  => Token::Operator('…'),
+            "dotso"  => Token::Operator('…'),
+            "hdotsfor" => Token::HDotsFor,
             "circ"   => Token::Operator('∘'),
             "bullet" => Token::Operator('∙'),
             "star"   => Token::Operator('⋆'),
@@ -418,7 +599,7 @@ impl Token {
             "cup"    => Token::Operator('∪'),
             "mid"    => Token::Operator('\u{2223}'),
             "nmid"   => Token::Operator('\u{2224}'),
-            "|"      => Token::Paren("\u{2225}"),
+            "|"      => Token::Paren("‖"),
             "parallel" => Token::Operator('∥'),
             "perp"   => Token::Operator('⊥'),
             "forall" => Token::Operator('∀'),
@@ -520,7 +701,7 @@ impl Token {
             "Yleft"               => Token::Operator('⤙'),
             "Yright"              => Token::Operator('⤚'),
             "in"     => Token::Operator('\u{2208}'),
-            "ni"     => Token::Operator('\u{2209}'),
+            "ni"     => Token::Operator('\u{220b}'),
             "notin"  => Token::Operator('\u{2209}'),
             "subset" => Token::Operator('\u{2282}'),
             "supset" => Token::Operator('\u{2283}'),
@@ -549,7 +730,112 @@ impl Token {
             "Vdash"     => Token::Operator('⊩'),
             "models"    => Token::Operator('⊨'),
             "slashed"   => Token::Slashed,
-            command  => Token::Command(command.to_owned()),
+            "not"       => Token::Not,
+            "boxed"     => Token::Enclose("box"),
+            "cancel"    => Token::Enclose("updiagonalstrike"),
+            "bcancel"   => Token::Enclose("downdiagonalstrike"),
+            "xcancel"   => Token::Enclose("updiagonalstrike downdiagonalstrike"),
+            "sout"      => Token::Enclose("horizontalstrike"),
+            "cancelto"  => Token::CancelTo,
+            "fbox"      => Token::Fbox,
+            "framebox"  => Token::Fbox,
+            "enclose"   => Token::GenEnclose,
+            "bbox"      => Token::BBox,
+            "phantom"   => Token::Phantom,
+            "hphantom"  => Token::HPhantom,
+            "vphantom"  => Token::VPhantom,
+            "smash"     => Token::Smash,
+            "mathstrut" => Token::MathStrut,
+            "strut"     => Token::Strut,
+            "mathrlap"  => Token::Lap(None),
+            "rlap"      => Token::Lap(None),
+            "mathllap"  => Token::Lap(Some("-1width")),
+            "llap"      => Token::Lap(Some("-1width")),
+            "mathclap"  => Token::Lap(Some("-0.5width")),
+            "clap"      => Token::Lap(Some("-0.5width")),
+            "href"      => Token::Href,
+            "url"       => Token::Url,
+            "class"     => Token::Html("class"),
+            "cssId"     => Token::Html("id"),
+            "style"     => Token::Html("style"),
+            "htmlClass" => Token::Html("class"),
+            "htmlId"    => Token::Html("id"),
+            "htmlStyle" => Token::Html("style"),
+            "htmlData"  => Token::HtmlData,
+            "color"     => Token::Color,
+            "textcolor" => Token::TextColor,
+            "colorbox"  => Token::ColorBox,
+            "fcolorbox" => Token::FColorBox,
+            command  => match symbol::lookup(command) {
+                Some((s, class)) => Token::from_symbol(command, s, class),
+                None => Token::Command(command.to_owned()),
+            },
+        }
+    }
+
+    /// unicode-math の記号の表に載っているコマンドを, そのクラスに従ってトークンにする.
+    fn from_symbol(command: &str, s: &'static str, class: Class) -> Token {
+        let c = s.chars().next().unwrap_or_default();
+        match class {
+            // `\mupalpha` などは直立体
+            Class::Alpha if command.starts_with("mup") => Token::Letter(c, Variant::Normal),
+            Class::Alpha => Token::Letter(c, Variant::Italic),
+            Class::Ord   => match Token::from_char(c) {
+                Token::Number(n) => Token::Number(n),
+                _ => Token::Letter(c, Variant::Normal),
+            },
+            Class::Op    => match Token::from_char(c) {
+                Token::Integral(c) => Token::Integral(c),
+                _ => Token::BigOp(c),
+            },
+            Class::Bin | Class::Rel | Class::Punct => Token::Operator(c),
+            Class::Open | Class::Close | Class::Fence => Token::Paren(s),
         }
     }
 }
+
+/// `\infty` や `\square` のように `<mi>` として扱う記号かどうか.
+fn is_ordinary_symbol(c: char) -> bool {
+    match c {
+        '∅' | '∞' | '∎' | '∠' | '∡' | '∢' | '†' | '‡' | '✓' | '✗' => true,
+        // Geometric Shapes
+        '\u{25a0}'..='\u{25ff}' => true,
+        // トランプのマークと音楽記号
+        '\u{2660}'..='\u{266f}' => true,
+        _ => false,
+    }
+}
+
+/// MathML の演算子辞書に載っている文字かどうか.
+fn is_operator(c: char) -> bool {
+    match c {
+        '!' | '%' | '&' | '(' | ')' | '*' | '+' | ',' | '-' | '.' | '/' | ':' | ';' | '<' | '=' | '>' | '?' 
+            | '[' | '\\' | ']' | '^' | '_' | '{' | '|' | '}' | '~' => true,
+        '¬' | '±' | '·' | '×' | '÷' | '‖' | '′' | '″' | '‴' | '⁗' | '⁡' | '⁢' | '⁣' | '⁤' => true,
+        // Arrows, Mathematical Operators
+        '\u{2190}'..='\u{22ff}' => true,
+        // 天井関数, 床関数, 山括弧
+        '\u{2308}'..='\u{230b}' | '\u{2329}' | '\u{232a}' => true,
+        // Miscellaneous Mathematical Symbols-A, Supplemental Arrows-A, -B,
+        // Miscellaneous Mathematical Symbols-B, Supplemental Mathematical Operators
+        '\u{27c0}'..='\u{27ff}' | '\u{2900}'..='\u{2aff}' => true,
+        // Miscellaneous Symbols and Arrows のうち矢印
+        '\u{2b00}'..='\u{2b11}' | '\u{2b30}'..='\u{2b4c}' => true,
+        // CJK の括弧
+        '\u{3008}'..='\u{3011}' | '\u{3014}'..='\u{301b}' => true,
+        _ => false,
+    }
+}
+
+/// テキストモードのトークン
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextToken {
+    Char(char),
+    Space,
+    LBrace,
+    RBrace,
+    Command(String),
+    /// `$..$` や `\(..\)` で囲まれた数式
+    Math(String),
+    EOF,
+}